@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LegacyItemUuid } from "./LegacyItemUuid";
+import type { ProjUuid } from "./ProjUuid";
+import type { UserUuid } from "./UserUuid";
+
+export type Membership = { user_id: UserUuid, project_ids: Array<ProjUuid>, legacy_id: LegacyItemUuid | null, };
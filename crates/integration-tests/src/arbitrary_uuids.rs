@@ -0,0 +1,40 @@
+//! Tests for generating typed UUIDs with arbitrary, as used by fuzzers.
+
+use arbitrary::{Arbitrary, Unstructured};
+use newtype_uuid::GenericUuid;
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+#[test]
+fn test_arbitrary_covers_edge_cases() {
+    let mut seen_nil = false;
+    let mut seen_max = false;
+    let mut seen_non_rfc = false;
+    let mut seen_versions = [false; 16];
+    // Walk through all shapes, with a variety of bytes for the rest.
+    for seed in 0..=255u8 {
+        let data: [u8; 64] = std::array::from_fn(|i| seed.wrapping_mul(31).wrapping_add(i as u8));
+        let mut u = Unstructured::new(&data);
+        let uuid = UserUuid::arbitrary(&mut u).unwrap();
+        seen_nil |= uuid.is_nil();
+        seen_max |= uuid.is_max();
+        match uuid.as_untyped_uuid().get_variant() {
+            uuid::Variant::RFC4122 => seen_versions[uuid.get_version_num()] = true,
+            _ if !uuid.is_nil() && !uuid.is_max() => seen_non_rfc = true,
+            _ => {}
+        }
+    }
+    assert!(seen_nil, "nil UUID generated");
+    assert!(seen_max, "max UUID generated");
+    assert!(seen_non_rfc, "non-RFC variant generated");
+    assert_eq!(seen_versions[1..=8], [true; 8], "all versions generated");
+
+    // Running out of data still produces a value.
+    let mut u = Unstructured::new(&[]);
+    UserUuid::arbitrary(&mut u).unwrap();
+}
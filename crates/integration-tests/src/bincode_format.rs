@@ -0,0 +1,27 @@
+//! Tests for the bincode 2 encoding of typed UUIDs.
+
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+#[test]
+fn test_bincode2_round_trip() {
+    let uuid = UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    let config = bincode2::config::standard();
+    let mut buf = [0u8; 32];
+    let len = bincode2::encode_into_slice(uuid, &mut buf, config).unwrap();
+    assert_eq!(&buf[..len], uuid.as_bytes());
+
+    let (decoded, read): (UserUuid, _) = bincode2::decode_from_slice(&buf[..len], config).unwrap();
+    assert_eq!(decoded, uuid);
+    assert_eq!(read, 16);
+    let (decoded, _): (UserUuid, _) =
+        bincode2::borrow_decode_from_slice(&buf[..len], config).unwrap();
+    assert_eq!(decoded, uuid);
+
+    bincode2::decode_from_slice::<UserUuid, _>(&buf[..15], config).unwrap_err();
+}
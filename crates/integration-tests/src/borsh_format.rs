@@ -0,0 +1,21 @@
+//! Tests for the borsh encoding of typed UUIDs.
+
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+#[test]
+fn test_borsh_round_trip() {
+    let uuid = UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    let encoded = borsh::to_vec(&uuid).unwrap();
+    assert_eq!(encoded, uuid.as_bytes());
+    // The encoding matches that of the untyped UUID.
+    assert_eq!(encoded, borsh::to_vec(uuid.as_bytes()).unwrap());
+    assert_eq!(borsh::from_slice::<UserUuid>(&encoded).unwrap(), uuid);
+
+    borsh::from_slice::<UserUuid>(&encoded[..15]).unwrap_err();
+}
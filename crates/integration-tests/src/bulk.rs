@@ -0,0 +1,69 @@
+//! Tests for parsing and formatting many typed UUIDs at once.
+
+use newtype_uuid::{TypedUuid, TypedUuidKind};
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+#[test]
+fn test_parse_and_format_many() {
+    let inputs = [
+        "dffc3068-1cd6-47d5-b2f3-636b41b07084",
+        "01890A5D-AC96-774B-BCCE-B302099A8057",
+        "00000000000000000000000000000001",
+    ];
+    let uuids = UserUuid::parse_many(inputs).unwrap();
+    assert_eq!(
+        uuids,
+        [
+            UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084),
+            UserUuid::from_u128(0x01890a5d_ac96_774b_bcce_b302099a8057),
+            UserUuid::from_u128(1),
+        ]
+    );
+
+    let mut out = String::new();
+    TypedUuid::format_many_into(&uuids, ",", &mut out).unwrap();
+    assert_eq!(
+        out,
+        "dffc3068-1cd6-47d5-b2f3-636b41b07084,01890a5d-ac96-774b-bcce-b302099a8057,\
+         00000000-0000-0000-0000-000000000001",
+    );
+    out.clear();
+    UserUuid::format_many_into(&[], ",", &mut out).unwrap();
+    assert_eq!(out, "");
+
+    // On failure, the buffer is left as it was.
+    let mut buf = uuids.clone();
+    let error =
+        UserUuid::parse_many_into([inputs[0], inputs[1], "", inputs[2]], &mut buf).unwrap_err();
+    assert_eq!(error.index, 2);
+    assert_eq!(error.error.tag, UserKind::tag());
+    assert_eq!(buf, uuids);
+    TypedUuid::parse_many_into(&inputs[..1], &mut buf).unwrap();
+    assert_eq!(buf.len(), 4);
+}
+
+#[test]
+fn test_from_bytes_slice() {
+    let uuids = [
+        UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084),
+        UserUuid::nil(),
+        UserUuid::max(),
+    ];
+    let bytes: Vec<u8> = uuids.iter().flat_map(|uuid| *uuid.as_bytes()).collect();
+    assert_eq!(UserUuid::from_bytes_slice(&bytes).unwrap(), uuids);
+    assert!(UserUuid::from_bytes_slice(&[]).unwrap().is_empty());
+
+    let error = UserUuid::from_bytes_slice(&bytes[..40]).unwrap_err();
+    assert_eq!(error.len, 40);
+    assert_eq!(error.tag, UserKind::tag());
+    assert_eq!(
+        error.to_string(),
+        "error parsing UUIDs (user): byte length 40 is not a multiple of 16"
+    );
+}
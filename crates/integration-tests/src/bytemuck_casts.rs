@@ -0,0 +1,33 @@
+//! Tests for casting byte buffers to typed UUIDs with bytemuck.
+
+use bytemuck::TransparentWrapper;
+use newtype_uuid::{GenericUuid, TypedUuid};
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use uuid::Uuid;
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+#[test]
+fn test_bytemuck_cast() {
+    let mut bytes = [0u8; 32];
+    bytes[15] = 1;
+    bytes[16..].fill(0xff);
+    let uuids: &[UserUuid] = bytemuck::cast_slice(&bytes);
+    assert_eq!(uuids, [TypedUuid::from_u128(1), TypedUuid::max()]);
+    assert_eq!(bytemuck::cast_slice::<UserUuid, u8>(uuids), bytes);
+    bytemuck::try_cast_slice::<u8, UserUuid>(&bytes[..31]).unwrap_err();
+
+    let untyped = TypedUuid::peel_slice(uuids);
+    assert_eq!(untyped, [Uuid::from_u128(1), Uuid::max()]);
+    assert_eq!(UserUuid::wrap_slice(untyped), uuids);
+
+    let untyped = Uuid::from_u128(1);
+    let typed: &UserUuid = TypedUuid::wrap_ref(&untyped);
+    assert_eq!(typed.as_untyped_uuid(), &untyped);
+    let zeroed = <UserUuid as bytemuck::Zeroable>::zeroed();
+    assert_eq!(zeroed, TypedUuid::nil());
+}
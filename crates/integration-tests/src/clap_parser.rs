@@ -0,0 +1,60 @@
+//! Tests for parsing typed UUIDs as clap arguments.
+
+use clap::{Command, ValueHint, error::ErrorKind};
+use newtype_uuid::{TypedUuid, typed_uuid_arg};
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+#[test]
+fn test_clap_value_parser() {
+    let cmd = Command::new("test").arg(typed_uuid_arg::<UserKind>("id").long("id"));
+    let uuid = UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+
+    for input in [
+        "dffc3068-1cd6-47d5-b2f3-636b41b07084",
+        "user:dffc3068-1cd6-47d5-b2f3-636b41b07084",
+        "urn:uuid:dffc3068-1cd6-47d5-b2f3-636b41b07084",
+    ] {
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(["test", "--id", input])
+            .unwrap();
+        assert_eq!(matches.get_one::<TypedUuid<UserKind>>("id"), Some(&uuid));
+    }
+
+    for (input, expected) in [
+        (
+            "not-a-uuid",
+            "expected a user UUID, optionally prefixed with `user:`",
+        ),
+        (
+            "other:dffc3068-1cd6-47d5-b2f3-636b41b07084",
+            "expected tag `user`, found `other`",
+        ),
+        ("user:not-a-uuid", "invalid user UUID"),
+    ] {
+        let error = cmd
+            .clone()
+            .try_get_matches_from(["test", "--id", input])
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::ValueValidation);
+        let message = error.to_string();
+        assert!(
+            message.contains(&format!("invalid value '{input}' for '--id <USER_UUID>'")),
+            "unexpected error: {message}"
+        );
+        assert!(message.contains(expected), "unexpected error: {message}");
+    }
+
+    let help = cmd.clone().render_help().to_string();
+    assert!(help.contains("--id <USER_UUID>"), "unexpected help: {help}");
+
+    let arg = cmd.get_arguments().next().unwrap();
+    assert_eq!(arg.get_value_hint(), ValueHint::Other);
+    assert!(arg.get_possible_values().is_empty());
+}
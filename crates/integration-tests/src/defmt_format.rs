@@ -0,0 +1,64 @@
+//! defmt formatting tests for newtype-uuid.
+
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use std::cell::RefCell;
+
+impl_typed_uuid_kinds! {
+    settings = {
+        defmt1 = {},
+    },
+    kinds = {
+        User = {},
+    }
+}
+
+const UUID_STR: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+/// A logger that records encoded bytes for the current thread.
+#[defmt::global_logger]
+struct TestLogger;
+
+thread_local! {
+    static BYTES: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+unsafe impl defmt::Logger for TestLogger {
+    fn acquire() {}
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {}
+
+    unsafe fn write(bytes: &[u8]) {
+        BYTES.with(|b| b.borrow_mut().extend_from_slice(bytes));
+    }
+}
+
+/// Formats `value` and returns the bytes written.
+fn encode(value: &impl defmt::Format) -> Vec<u8> {
+    BYTES.with(|b| b.borrow_mut().clear());
+    // Logging macros also write a timestamp, which requires defmt's linker
+    // script. Formatting the value directly doesn't.
+    value.format(defmt::export::make_formatter());
+    BYTES.with(|b| b.take())
+}
+
+#[test]
+fn test_format() {
+    let uuid: UserUuid = UUID_STR.parse().unwrap();
+    let bytes = encode(&uuid);
+
+    // The format string and the tag are both interned, so they're sent as
+    // 2-byte indexes. The UUID follows as little-endian integers.
+    assert_eq!(bytes.len(), 20);
+    let expected: Vec<u8> = [
+        &0x67e55044_u32.to_le_bytes()[..],
+        &0x10b1_u16.to_le_bytes(),
+        &0x426f_u16.to_le_bytes(),
+        &0x9247_u16.to_le_bytes(),
+        &0xbb68_u16.to_le_bytes(),
+        &0x0e5fe0c8_u32.to_le_bytes(),
+    ]
+    .concat();
+    assert_eq!(bytes[4..], expected);
+}
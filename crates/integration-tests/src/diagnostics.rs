@@ -0,0 +1,62 @@
+//! Tests for miette diagnostics.
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use newtype_uuid::{ParseErrorKind, TypedUuid, TypedUuidKind, TypedUuidTag};
+
+enum UserKind {}
+
+impl TypedUuidKind for UserKind {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("user");
+        TAG
+    }
+}
+
+const SOURCE: &str = r#"[owner]
+name = "admin"
+id = "dffc3068-1cd6-47d5-b2f3-636b41b0708x"
+
+[member]
+id = "user:dffc3068-1cd6-47d5-b2f3"
+"#;
+
+/// Returns the span of the `n`th quoted `id` value in `SOURCE`, excluding
+/// quotes.
+fn id_span(n: usize) -> (&'static str, (usize, usize)) {
+    let (start, _) = SOURCE.match_indices("id = \"").nth(n).unwrap();
+    let start = start + "id = \"".len();
+    let len = SOURCE[start..].find('"').unwrap();
+    (&SOURCE[start..start + len], (start, len))
+}
+
+fn render(report: Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor())
+        .with_width(80)
+        .render_report(&mut out, report.as_ref())
+        .unwrap();
+    out
+}
+
+#[test]
+fn test_parse_error_diagnostics() {
+    let (input, span) = id_span(0);
+    let error = TypedUuid::<UserKind>::parse_spanned(input, span).unwrap_err();
+    assert_eq!(
+        error.kind(),
+        ParseErrorKind::InvalidChar {
+            character: 'x',
+            index: 35
+        }
+    );
+    let invalid_char = render(Report::new(error).with_source_code(SOURCE));
+
+    let (input, span) = id_span(1);
+    let error = TypedUuid::<UserKind>::parse_prefixed(input)
+        .unwrap_err()
+        .with_span(span);
+    let invalid_length = render(Report::new(error).with_source_code(SOURCE));
+
+    let output = format!("{invalid_char}\n{invalid_length}");
+    expectorate::assert_contents("outputs/typed-uuid-miette.txt", &output);
+}
@@ -0,0 +1,110 @@
+//! GraphQL scalar tests for newtype-uuid, via async-graphql.
+
+use async_graphql::{
+    Description, EmptyMutation, EmptySubscription, InputType, Object, OutputType, Pos, Schema,
+    Value,
+};
+use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    settings = {
+        async_graphql7 = {
+            attrs = [
+                #[cfg(feature = "internal-async-graphql7-tests")],
+            ],
+        },
+    },
+    kinds = {
+        User = {},
+        Project = { alias = ProjUuid },
+    }
+}
+
+/// A kind without an alias, so its scalar name is derived from the tag.
+#[derive(Debug)]
+enum LegacyKind {}
+
+impl TypedUuidKind for LegacyKind {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("legacy_item");
+        TAG
+    }
+}
+
+impl Description for LegacyKind {
+    fn description() -> &'static str {
+        "A UUID from the legacy system."
+    }
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn user(&self, id: UserUuid) -> UserUuid {
+        id
+    }
+
+    async fn projects(&self) -> Vec<ProjUuid> {
+        Vec::new()
+    }
+
+    async fn legacy(&self, id: Option<TypedUuid<LegacyKind>>) -> Option<TypedUuid<LegacyKind>> {
+        id
+    }
+}
+
+fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::new(Query, EmptyMutation, EmptySubscription)
+}
+
+#[test]
+fn test_graphql_sdl_snapshot() {
+    expectorate::assert_contents("outputs/typed-uuid-graphql.graphql", &schema().sdl());
+}
+
+#[test]
+fn test_scalar_names() {
+    assert_eq!(<UserUuid as InputType>::type_name(), "UserUuid");
+    assert_eq!(<UserUuid as OutputType>::type_name(), "UserUuid");
+    assert_eq!(<ProjUuid as InputType>::type_name(), "ProjUuid");
+    assert_eq!(
+        <TypedUuid<LegacyKind> as InputType>::type_name(),
+        "LegacyItemUuid"
+    );
+    assert_eq!(
+        <TypedUuid<LegacyKind> as OutputType>::qualified_type_name(),
+        "LegacyItemUuid!"
+    );
+}
+
+#[test]
+fn test_scalar_round_trip() {
+    let uuid: UserUuid = "a0d4e6b1-5c4f-4f8a-9d2e-3b7c1f0e8a6d".parse().unwrap();
+    let value = InputType::to_value(&uuid);
+    assert_eq!(
+        value,
+        Value::String("a0d4e6b1-5c4f-4f8a-9d2e-3b7c1f0e8a6d".to_owned())
+    );
+    assert_eq!(<UserUuid as InputType>::parse(Some(value)).unwrap(), uuid);
+}
+
+#[test]
+fn test_scalar_parse_errors() {
+    let message = |value: Value| {
+        <UserUuid as InputType>::parse(Some(value))
+            .unwrap_err()
+            .into_server_error(Pos::default())
+            .message
+    };
+
+    assert_eq!(
+        message(Value::String("not-a-uuid".to_owned())),
+        r#"Failed to parse "UserUuid": error parsing UUID (user) from "not-a-uuid": invalid character 'n' at index 0"#
+    );
+    assert_eq!(
+        message(Value::Number(42.into())),
+        r#"Expected input type "UserUuid", found 42."#
+    );
+}
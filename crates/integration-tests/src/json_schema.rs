@@ -20,7 +20,11 @@ impl_typed_uuid_kinds! {
         },
     },
     kinds = {
-        My = {},
+        My = {
+            constants = {
+                ROOT = "001de000-05e4-4000-8000-000000000000",
+            },
+        },
         Test = {},
         Another = {},
     }
@@ -100,6 +104,22 @@ fn test_schemars_macro_integration() {
     assert_eq!(schema, expected_schema);
 }
 
+#[test]
+fn test_macro_generated_constants() {
+    assert_eq!(
+        MyUuid::ROOT.to_string(),
+        "001de000-05e4-4000-8000-000000000000",
+    );
+
+    // The constants are exported as examples for the kind's schema.
+    let mut generator = schemars::r#gen::SchemaGenerator::default();
+    let schema = MyKind::json_schema(&mut generator).into_object();
+    assert_eq!(
+        schema.metadata.expect("metadata is set").examples,
+        [serde_json::json!("001de000-05e4-4000-8000-000000000000")],
+    );
+}
+
 #[test]
 fn test_macro_generated_tags() {
     // Test that the generated kinds have the correct tags
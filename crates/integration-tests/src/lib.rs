@@ -1,9 +1,45 @@
 //! Integration tests.
 
+#[cfg(all(test, feature = "internal-arbitrary1-tests"))]
+mod arbitrary_uuids;
+#[cfg(all(test, feature = "internal-bincode2-tests"))]
+mod bincode_format;
+#[cfg(all(test, feature = "internal-borsh1-tests"))]
+mod borsh_format;
+#[cfg(test)]
+mod bulk;
+#[cfg(all(test, feature = "internal-bytemuck1-tests"))]
+mod bytemuck_casts;
+#[cfg(all(test, feature = "internal-clap4-tests"))]
+mod clap_parser;
+#[cfg(all(test, feature = "internal-defmt1-tests"))]
+mod defmt_format;
+#[cfg(all(test, feature = "internal-miette7-tests"))]
+mod diagnostics;
+#[cfg(all(test, feature = "internal-async-graphql7-tests"))]
+mod graphql;
 #[cfg(all(test, feature = "internal-schemars08-tests"))]
 mod json_schema;
+#[cfg(all(test, feature = "internal-utoipa5-tests"))]
+mod openapi;
+#[cfg(test)]
+mod parse_errors;
 #[cfg(all(test, feature = "internal-proptest1-tests"))]
 mod proptests;
+#[cfg(all(test, feature = "internal-pyo3028-tests"))]
+mod python;
+#[cfg(all(test, feature = "internal-quickcheck1-tests"))]
+mod quickchecks;
+#[cfg(all(test, feature = "internal-rkyv08-tests"))]
+mod rkyv_archive;
+#[cfg(all(test, feature = "internal-serde-tests"))]
+mod serde_formats;
 pub mod snapshot_utils;
+#[cfg(all(test, feature = "internal-tracing01-tests"))]
+mod tracing_fields;
+#[cfg(all(test, feature = "internal-ts-rs11-tests"))]
+mod typescript;
 #[cfg(test)]
 mod ui;
+#[cfg(all(test, feature = "internal-valuable01-tests"))]
+mod valuable_fields;
@@ -0,0 +1,133 @@
+//! OpenAPI schema tests for newtype-uuid, via utoipa.
+
+use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use utoipa::{
+    OpenApi, PartialSchema, ToSchema,
+    openapi::{RefOr, Schema},
+};
+
+impl_typed_uuid_kinds! {
+    settings = {
+        utoipa5 = {
+            attrs = [
+                #[cfg(feature = "internal-utoipa5-tests")],
+            ],
+            rust_type = {
+                crate = "my-crate",
+                version = "1.0.0",
+                path = "my_crate::types",
+            },
+        },
+    },
+    kinds = {
+        My = {},
+        Test = {},
+    }
+}
+
+/// A kind without an alias or `x-rust-type`, so its schema can't be lifted.
+#[derive(Debug)]
+enum PlainKind {}
+
+impl TypedUuidKind for PlainKind {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("plain");
+        TAG
+    }
+}
+
+impl PartialSchema for PlainKind {
+    fn schema() -> RefOr<Schema> {
+        utoipa::openapi::ObjectBuilder::new().into()
+    }
+}
+
+impl ToSchema for PlainKind {}
+
+// utoipa's derive macro requires types with generic parameters to implement
+// internal traits, so refer to `TypedUuid<T>` via a type alias.
+type PlainUuid = TypedUuid<PlainKind>;
+
+#[derive(ToSchema)]
+#[expect(dead_code)]
+struct MyPathStruct {
+    id: MyUuid,
+    test_id: TestUuid,
+    plain_id: PlainUuid,
+}
+
+#[derive(OpenApi)]
+#[openapi(components(schemas(MyPathStruct)))]
+struct ApiDoc;
+
+#[test]
+fn test_openapi_snapshot() {
+    // Only snapshot the components, since the rest of the document is
+    // derived from this crate's metadata.
+    let components = serde_json::to_string_pretty(&ApiDoc::openapi().components).unwrap();
+    expectorate::assert_contents("outputs/typed-uuid-openapi.json", &components);
+}
+
+#[test]
+fn test_lifted_schema() {
+    // With an alias and x-rust-type, the schema is lifted to refer to the
+    // alias directly, and the kind's own schema isn't registered.
+    assert_eq!(MyUuid::name(), "MyUuid");
+    assert_eq!(
+        serde_json::to_value(MyUuid::schema()).unwrap(),
+        serde_json::json!({
+            "type": "string",
+            "format": "uuid",
+            "x-rust-type": {
+                "crate": "my-crate",
+                "version": "1.0.0",
+                "path": "my_crate::types::MyUuid",
+            },
+        }),
+    );
+
+    let mut schemas = Vec::new();
+    MyUuid::schemas(&mut schemas);
+    assert!(schemas.is_empty());
+}
+
+#[test]
+fn test_unlifted_schema() {
+    // Without an alias, the schema refers to newtype_uuid::TypedUuid, with
+    // the kind as a parameter.
+    assert_eq!(PlainUuid::name(), "TypedUuidForPlainKind");
+    assert_eq!(
+        serde_json::to_value(PlainUuid::schema()).unwrap(),
+        serde_json::json!({
+            "type": "string",
+            "format": "uuid",
+            "x-rust-type": {
+                "crate": "newtype-uuid",
+                "version": "1",
+                "path": "newtype_uuid::TypedUuid",
+                "parameters": [{ "$ref": "#/components/schemas/PlainKind" }],
+            },
+        }),
+    );
+
+    let mut schemas = Vec::new();
+    PlainUuid::schemas(&mut schemas);
+    let names: Vec<_> = schemas.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["PlainKind"]);
+}
+
+#[test]
+fn test_utoipa_macro_integration() {
+    assert_eq!(TestKind::name(), "TestKind");
+    assert_eq!(
+        serde_json::to_value(TestKind::schema()).unwrap(),
+        serde_json::json!({
+            "x-rust-type": {
+                "crate": "my-crate",
+                "version": "1.0.0",
+                "path": "my_crate::types::TestKind",
+            },
+        }),
+    );
+}
@@ -0,0 +1,89 @@
+//! Tests for the errors returned when parsing typed UUIDs.
+
+use newtype_uuid::{CheckedParseError, ParseErrorKind, TypedUuidKind};
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use std::error::Error;
+use uuid::Version;
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+fn parse(input: &str) -> newtype_uuid::ParseError {
+    input.parse::<UserUuid>().unwrap_err()
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse("dffc3068-1cd6-47d5-b2f3-636b41b0708");
+    assert_eq!(error.kind(), ParseErrorKind::InvalidLength { len: 35 });
+    assert_eq!(error.input(), "dffc3068-1cd6-47d5-b2f3-636b41b0708");
+    assert_eq!(
+        error.to_string(),
+        "error parsing UUID (user) from \"dffc3068-1cd6-47d5-b2f3-636b41b0708\": \
+         invalid length 35",
+    );
+    assert!(error.source().is_some());
+
+    assert_eq!(
+        parse("dffc3068-1cd6-47d5-b2f3-636b41b0708x").kind(),
+        ParseErrorKind::InvalidChar {
+            character: 'x',
+            index: 35
+        },
+    );
+    assert_eq!(
+        parse("urn:uuid:dffc3068-1cd6-47d5-b2f3-636b41b0708é").kind(),
+        ParseErrorKind::InvalidChar {
+            character: 'é',
+            index: 44
+        },
+    );
+    assert_eq!(
+        parse("dffc30681-cd6-47d5-b2f3-636b41b07084").kind(),
+        ParseErrorKind::InvalidGroups,
+    );
+
+    // Long inputs are truncated.
+    let error = parse(&"é".repeat(40));
+    assert_eq!(error.input().len(), 67);
+    assert!(error.input().ends_with("é..."));
+}
+
+#[test]
+fn test_checked_parse_error() {
+    let error = UserUuid::parse_prefixed("other:dffc3068-1cd6-47d5-b2f3-636b41b07084").unwrap_err();
+    assert!(matches!(error, CheckedParseError::TagMismatch { .. }));
+    assert_eq!(error.tag(), &UserKind::tag());
+    assert!(error.source().is_none());
+    assert_eq!(
+        UserUuid::parse_prefixed("user:dffc3068-1cd6-47d5-b2f3-636b41b07084").unwrap(),
+        UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084),
+    );
+    // Errors in the UUID itself are reported relative to the whole input.
+    let CheckedParseError::Parse(error) = UserUuid::parse_prefixed("user:bogus").unwrap_err()
+    else {
+        panic!("expected a parse error");
+    };
+    assert_eq!(
+        error.kind(),
+        ParseErrorKind::InvalidChar {
+            character: 'o',
+            index: 6
+        },
+    );
+    assert_eq!(error.input(), "user:bogus");
+
+    let error = UserUuid::parse_version("01890a5d-ac96-774b-bcce-b302099a8057", Version::Random)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error parsing UUID (user): expected a version 4 UUID, found version 7",
+    );
+
+    let error = UserUuid::parse_non_nil("00000000000000000000000000000000").unwrap_err();
+    assert!(matches!(error, CheckedParseError::NilDisallowed { .. }));
+    assert!(UserUuid::parse_non_nil("00000000000000000000000000000001").is_ok());
+}
@@ -1,6 +1,10 @@
 //! Tests for the property-based infrastructure.
 
-use newtype_uuid::{GenericUuid, TypedUuid, TypedUuidKind, TypedUuidTag};
+use newtype_uuid::{
+    GenericUuid, TypedUuid, TypedUuidKind, TypedUuidParams, TypedUuidTag, UuidVersionParams,
+    typed_uuid_strategy_with,
+};
+use proptest::{collection::vec, prelude::*};
 use test_strategy::proptest;
 use uuid::Version;
 
@@ -31,3 +35,107 @@ fn prop_as_bytes(typed_uuid: TypedUuid<MyKind>) {
     let untyped_uuid_vec: Vec<u8> = typed_uuid.into_untyped_uuid().into();
     assert_eq!(typed_uuid_vec, untyped_uuid_vec);
 }
+
+const START_2024_MILLIS: u64 = 1_704_067_200_000;
+const END_2024_MILLIS: u64 = 1_735_689_599_999;
+
+fn v7_2024_params() -> TypedUuidParams {
+    TypedUuidParams::new().with_versions([UuidVersionParams::V7 {
+        unix_millis: START_2024_MILLIS..=END_2024_MILLIS,
+    }])
+}
+
+/// Ensure that v7 UUIDs have timestamps within the requested range.
+#[proptest]
+fn prop_v7_in_range(
+    #[strategy(typed_uuid_strategy_with::<MyKind>(v7_2024_params()))] uuid: TypedUuid<MyKind>,
+) {
+    assert_eq!(uuid.get_version(), Some(Version::SortRand));
+    let (secs, nanos) = uuid
+        .as_untyped_uuid()
+        .get_timestamp()
+        .expect("v7 UUIDs have timestamps")
+        .to_unix();
+    let millis = secs * 1000 + u64::from(nanos) / 1_000_000;
+    assert!(
+        (START_2024_MILLIS..=END_2024_MILLIS).contains(&millis),
+        "timestamp {millis} out of range"
+    );
+}
+
+/// Ensure that a mix of versions only produces the requested versions.
+#[proptest]
+fn prop_mixed_versions(
+    #[any(TypedUuidParams::new().with_versions([
+        UuidVersionParams::V4,
+        UuidVersionParams::V7 { unix_millis: 0..=END_2024_MILLIS },
+    ]))]
+    uuid: TypedUuid<MyKind>,
+) {
+    assert!(
+        matches!(uuid.get_version_num(), 4 | 7),
+        "unexpected version for {uuid}"
+    );
+}
+
+/// Ensure that pooled UUIDs are drawn from the pool.
+#[proptest]
+fn prop_pool(
+    #[strategy(vec(
+        typed_uuid_strategy_with::<MyKind>(TypedUuidParams::new().with_pool(pool())),
+        16,
+    ))]
+    uuids: Vec<TypedUuid<MyKind>>,
+) {
+    let pool = pool();
+    for uuid in &uuids {
+        assert!(pool.contains(uuid), "{uuid} not in pool");
+    }
+    // 16 draws from a pool of 3 must collide.
+    let mut deduped = uuids.clone();
+    deduped.sort();
+    deduped.dedup();
+    assert!(deduped.len() < uuids.len());
+}
+
+fn pool() -> Vec<TypedUuid<MyKind>> {
+    vec![
+        TypedUuid::from_u128(0x1),
+        TypedUuid::from_u128(0x2),
+        TypedUuid::from_u128(0x3),
+    ]
+}
+
+/// Ensure that the nil and max UUIDs are generated when requested.
+#[test]
+fn test_nil_and_max() {
+    let params = TypedUuidParams::new().with_nil(true).with_max(true);
+    let strategy = typed_uuid_strategy_with::<MyKind>(params);
+    let mut runner = proptest::test_runner::TestRunner::deterministic();
+
+    let (mut saw_nil, mut saw_max) = (false, false);
+    for _ in 0..1000 {
+        let uuid = strategy.new_tree(&mut runner).unwrap().current();
+        saw_nil |= uuid.is_nil();
+        saw_max |= uuid.is_max();
+        if !uuid.is_nil() && !uuid.is_max() {
+            assert_eq!(uuid.get_version(), Some(Version::Random));
+        }
+    }
+    assert!(saw_nil, "nil UUID generated");
+    assert!(saw_max, "max UUID generated");
+}
+
+#[test]
+#[should_panic = "at least one version must be specified"]
+fn test_empty_versions() {
+    _ = TypedUuidParams::new().with_versions([]);
+}
+
+#[test]
+#[should_panic = "must fit within 48 bits"]
+fn test_v7_out_of_range() {
+    _ = TypedUuidParams::new().with_versions([UuidVersionParams::V7 {
+        unix_millis: 0..=u64::MAX,
+    }]);
+}
@@ -0,0 +1,77 @@
+//! Python conversion tests for newtype-uuid, via pyo3 with an embedded
+//! interpreter.
+
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use pyo3::{
+    IntoPyObject, PyResult, Python,
+    exceptions::{PyTypeError, PyValueError},
+    types::{PyAnyMethods, PyDict, PyDictMethods},
+};
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+const UUID_STR: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+#[test]
+fn test_round_trip() -> PyResult<()> {
+    let uuid: UserUuid = UUID_STR.parse().unwrap();
+
+    Python::attach(|py| {
+        let obj = uuid.into_pyobject(py)?;
+        let locals = PyDict::new(py);
+        locals.set_item("obj", &obj)?;
+        py.run(
+            c"import uuid; assert isinstance(obj, uuid.UUID), type(obj)",
+            None,
+            Some(&locals),
+        )?;
+        assert_eq!(obj.str()?.extract::<String>()?, UUID_STR);
+
+        // References convert the same way.
+        assert!((&uuid).into_pyobject(py)?.eq(&obj)?);
+
+        assert_eq!(obj.extract::<UserUuid>()?, uuid);
+        Ok(())
+    })
+}
+
+#[test]
+fn test_extract_from_str() -> PyResult<()> {
+    Python::attach(|py| {
+        let obj = UUID_STR.into_pyobject(py)?;
+        assert_eq!(obj.extract::<UserUuid>()?, UUID_STR.parse().unwrap());
+        Ok(())
+    })
+}
+
+#[test]
+fn test_extract_errors() {
+    Python::attach(|py| {
+        let err = 42_i64
+            .into_pyobject(py)
+            .unwrap()
+            .extract::<UserUuid>()
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyTypeError>(py), "{err}");
+        assert_eq!(
+            err.to_string(),
+            "TypeError: expected a `uuid.UUID` or `str` for a user UUID, found `int`"
+        );
+
+        let err = "not-a-uuid"
+            .into_pyobject(py)
+            .unwrap()
+            .extract::<UserUuid>()
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py), "{err}");
+        let message = err.to_string();
+        assert!(
+            message.starts_with(r#"ValueError: invalid user UUID "not-a-uuid": "#),
+            "unexpected message: {message}"
+        );
+    });
+}
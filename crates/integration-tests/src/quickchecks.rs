@@ -0,0 +1,41 @@
+//! Tests for generating and shrinking typed UUIDs with quickcheck.
+
+use newtype_uuid::{GenericUuid, TypedUuid};
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use quickcheck::{Arbitrary, Gen};
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+#[test]
+fn test_quickcheck_covers_edge_cases_and_shrinks() {
+    let mut seen_nil = false;
+    let mut seen_max = false;
+    let mut seen_non_rfc = false;
+    let mut seen_versions = [false; 16];
+    for seed in 0..1024 {
+        let mut g = Gen::from_size_and_seed(100, seed);
+        let uuid = UserUuid::arbitrary(&mut g);
+        seen_nil |= uuid.is_nil();
+        seen_max |= uuid.is_max();
+        match uuid.as_untyped_uuid().get_variant() {
+            uuid::Variant::RFC4122 => seen_versions[uuid.get_version_num()] = true,
+            _ if !uuid.is_nil() && !uuid.is_max() => seen_non_rfc = true,
+            _ => {}
+        }
+    }
+    assert!(seen_nil, "nil UUID generated");
+    assert!(seen_max, "max UUID generated");
+    assert!(seen_non_rfc, "non-RFC variant generated");
+    assert_eq!(seen_versions[1..=8], [true; 8], "all versions generated");
+
+    // Shrinking starts with the nil UUID, and the nil UUID doesn't shrink.
+    let uuid = UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    let mut shrunk = uuid.shrink();
+    assert_eq!(shrunk.next(), Some(TypedUuid::nil()));
+    assert!(shrunk.all(|s| s.as_u128() < uuid.as_u128()));
+    assert_eq!(UserUuid::nil().shrink().next(), None);
+}
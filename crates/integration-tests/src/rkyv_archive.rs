@@ -0,0 +1,44 @@
+//! Tests for archiving typed UUIDs with rkyv.
+
+use newtype_uuid::{ArchivedTypedUuid, TypedUuid};
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use rkyv::{collections::swiss_table::ArchivedHashMap, rancor};
+use std::collections::HashMap;
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+#[test]
+fn test_rkyv_round_trip() {
+    let uuid = UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    let bytes = rkyv::to_bytes::<rancor::Error>(&uuid).unwrap();
+    assert_eq!(&bytes[..], uuid.as_bytes());
+
+    let archived = rkyv::access::<ArchivedTypedUuid<UserKind>, rancor::Error>(&bytes).unwrap();
+    assert_eq!(*archived, uuid);
+    assert_eq!(archived.to_typed_uuid(), uuid);
+    assert_eq!(format!("{archived:?}"), format!("{uuid:?}"));
+    let deserialized: UserUuid = rkyv::deserialize::<_, rancor::Error>(archived).unwrap();
+    assert_eq!(deserialized, uuid);
+
+    // Archived maps can be queried with native typed UUIDs.
+    let other = UserUuid::from_u128(1);
+    let map: HashMap<_, _> = [(uuid, 1u32), (other, 2u32)].into_iter().collect();
+    let bytes = rkyv::to_bytes::<rancor::Error>(&map).unwrap();
+    let archived = rkyv::access::<
+        ArchivedHashMap<ArchivedTypedUuid<UserKind>, rkyv::Archived<u32>>,
+        rancor::Error,
+    >(&bytes)
+    .unwrap();
+    let get = |key: &UserUuid| {
+        archived
+            .get_with(key, |key, archived| archived == key)
+            .map(|v| v.to_native())
+    };
+    assert_eq!(get(&uuid), Some(1));
+    assert_eq!(get(&other), Some(2));
+    assert_eq!(get(&TypedUuid::nil()), None);
+}
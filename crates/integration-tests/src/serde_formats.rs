@@ -0,0 +1,269 @@
+//! Round-trip tests for the serde helper modules across several formats.
+
+use newtype_uuid::{
+    TypedUuid, TypedUuidKind, TypedUuidStr, TypedUuidTag,
+    collections::{SortedTypedUuids, TimeOrderedUuidState, TypedUuidMap, TypedUuidSet},
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::fmt;
+
+#[derive(Debug)]
+enum MyKind {}
+
+impl TypedUuidKind for MyKind {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("my_kind");
+        TAG
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct AllFormats {
+    default: TypedUuid<MyKind>,
+    #[serde(with = "newtype_uuid::serde::bytes")]
+    bytes: TypedUuid<MyKind>,
+    #[serde(with = "newtype_uuid::serde::string")]
+    string: TypedUuid<MyKind>,
+    #[serde(with = "newtype_uuid::serde::prefixed")]
+    prefixed: TypedUuid<MyKind>,
+    #[serde(with = "newtype_uuid::serde::compact")]
+    compact: TypedUuid<MyKind>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Bytes(#[serde(with = "newtype_uuid::serde::bytes")] TypedUuid<MyKind>);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Prefixed(#[serde(with = "newtype_uuid::serde::prefixed")] TypedUuid<MyKind>);
+
+fn sample_uuids() -> impl Iterator<Item = TypedUuid<MyKind>> {
+    [
+        TypedUuid::nil(),
+        TypedUuid::max(),
+        TypedUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084),
+        TypedUuid::from_u128(0x01890a5d_ac96_774b_bcce_b302099a8057),
+    ]
+    .into_iter()
+}
+
+fn sample(uuid: TypedUuid<MyKind>) -> AllFormats {
+    AllFormats {
+        default: uuid,
+        bytes: uuid,
+        string: uuid,
+        prefixed: uuid,
+        compact: uuid,
+    }
+}
+
+fn round_trip<V, E>(
+    value: &V,
+    to: impl Fn(&V) -> Result<Vec<u8>, E>,
+    from: impl Fn(&[u8]) -> Result<V, E>,
+) where
+    V: Serialize + DeserializeOwned + PartialEq + fmt::Debug,
+    E: fmt::Debug,
+{
+    let encoded = to(value).expect("serialization succeeded");
+    let decoded = from(&encoded).expect("deserialization succeeded");
+    assert_eq!(&decoded, value);
+}
+
+#[test]
+fn test_round_trip_json() {
+    for uuid in sample_uuids() {
+        round_trip(&sample(uuid), serde_json::to_vec, |b| {
+            serde_json::from_slice(b)
+        });
+    }
+}
+
+#[test]
+fn test_round_trip_bincode() {
+    for uuid in sample_uuids() {
+        round_trip(&sample(uuid), bincode1::serialize, |b| {
+            bincode1::deserialize(b)
+        });
+    }
+}
+
+#[test]
+fn test_round_trip_postcard() {
+    for uuid in sample_uuids() {
+        round_trip(&sample(uuid), postcard::to_allocvec, |b| {
+            postcard::from_bytes(b)
+        });
+    }
+}
+
+#[test]
+fn test_round_trip_cbor() {
+    for uuid in sample_uuids() {
+        round_trip(
+            &sample(uuid),
+            |v| {
+                let mut buf = Vec::new();
+                ciborium::into_writer(v, &mut buf)
+                    .map(|()| buf)
+                    .map_err(|error| error.to_string())
+            },
+            |b| ciborium::from_reader(b).map_err(|error| error.to_string()),
+        );
+    }
+}
+
+#[test]
+fn test_json_representations() {
+    let uuid = TypedUuid::<MyKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    let value = serde_json::to_value(sample(uuid)).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "default": "dffc3068-1cd6-47d5-b2f3-636b41b07084",
+            "bytes": [
+                0xdf, 0xfc, 0x30, 0x68, 0x1c, 0xd6, 0x47, 0xd5,
+                0xb2, 0xf3, 0x63, 0x6b, 0x41, 0xb0, 0x70, 0x84,
+            ],
+            "string": "dffc3068-1cd6-47d5-b2f3-636b41b07084",
+            "prefixed": "my_kind:dffc3068-1cd6-47d5-b2f3-636b41b07084",
+            "compact": "3_wwaBzWR9Wy82NrQbBwhA",
+        })
+    );
+}
+
+#[test]
+fn test_binary_representations() {
+    let uuid = TypedUuid::<MyKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+
+    // bincode 1 writes a u64 length prefix before the bytes, and postcard a
+    // varint length prefix.
+    let encoded = bincode1::serialize(&Bytes(uuid)).unwrap();
+    assert_eq!(&encoded[..8], &16u64.to_le_bytes());
+    assert_eq!(&encoded[8..], uuid.as_bytes());
+
+    let encoded = postcard::to_allocvec(&Bytes(uuid)).unwrap();
+    assert_eq!(encoded[0], 16);
+    assert_eq!(&encoded[1..], uuid.as_bytes());
+
+    // CBOR stores a byte string (major type 2) of length 16.
+    let mut encoded = Vec::new();
+    ciborium::into_writer(&Bytes(uuid), &mut encoded).unwrap();
+    assert_eq!(encoded[0], 0x40 | 16);
+    assert_eq!(&encoded[1..], uuid.as_bytes());
+
+    // The string-based modules produce strings even in binary formats.
+    let encoded = postcard::to_allocvec(&Prefixed(uuid)).unwrap();
+    assert_eq!(
+        &encoded[1..],
+        b"my_kind:dffc3068-1cd6-47d5-b2f3-636b41b07084"
+    );
+}
+
+#[test]
+fn test_prefixed_errors() {
+    let error = serde_json::from_str::<Prefixed>(r#""other:dffc3068-1cd6-47d5-b2f3-636b41b07084""#)
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("expected tag `my_kind`, found `other`"),
+        "unexpected error: {error}"
+    );
+
+    let error =
+        serde_json::from_str::<Prefixed>(r#""dffc3068-1cd6-47d5-b2f3-636b41b07084""#).unwrap_err();
+    assert!(
+        error.to_string().contains("prefixed with `my_kind:`"),
+        "unexpected error: {error}"
+    );
+
+    serde_json::from_str::<Prefixed>(r#""my_kind:not-a-uuid""#).unwrap_err();
+}
+
+#[test]
+fn test_bytes_errors() {
+    serde_json::from_str::<Bytes>("[1, 2, 3]").unwrap_err();
+    serde_json::from_str::<Bytes>(&format!("{:?}", [0u8; 17])).unwrap_err();
+}
+
+#[test]
+fn test_collections() {
+    let a = TypedUuid::<MyKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    let b = TypedUuid::<MyKind>::from_u128(0x01890a5d_ac96_774b_bcce_b302099a8057);
+
+    // Maps are serialized as objects keyed by UUID strings.
+    let map: TypedUuidMap<MyKind, u32> = [(a, 1), (b, 2)].into_iter().collect();
+    assert_eq!(
+        serde_json::to_value(&map).unwrap(),
+        serde_json::json!({
+            "dffc3068-1cd6-47d5-b2f3-636b41b07084": 1,
+            "01890a5d-ac96-774b-bcce-b302099a8057": 2,
+        })
+    );
+    round_trip(&map, serde_json::to_vec, |b| serde_json::from_slice(b));
+    round_trip(&map, bincode1::serialize, |b| bincode1::deserialize(b));
+
+    let set: TypedUuidSet<MyKind, TimeOrderedUuidState> = [a, b].into_iter().collect();
+    round_trip(&set, serde_json::to_vec, |b| serde_json::from_slice(b));
+    round_trip(&set, postcard::to_allocvec, |b| postcard::from_bytes(b));
+}
+
+#[test]
+fn test_sorted_typed_uuids() {
+    let a = TypedUuid::<MyKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    let b = TypedUuid::<MyKind>::from_u128(0x01890a5d_ac96_774b_bcce_b302099a8057);
+    let c = TypedUuid::<MyKind>::from_u128(0x01890a5d_ac97_7d1e_8f2a_3b4c5d6e7f80);
+
+    // In JSON, the list is a sequence of UUID strings, and is sorted on the
+    // way in.
+    let uuids: SortedTypedUuids<MyKind> = [a, b, c].into_iter().collect();
+    assert_eq!(
+        serde_json::to_value(&uuids).unwrap(),
+        serde_json::json!([
+            "01890a5d-ac96-774b-bcce-b302099a8057",
+            "01890a5d-ac97-7d1e-8f2a-3b4c5d6e7f80",
+            "dffc3068-1cd6-47d5-b2f3-636b41b07084",
+        ])
+    );
+    let unsorted: SortedTypedUuids<MyKind> =
+        serde_json::from_value(serde_json::json!([a, c, b, a])).unwrap();
+    assert_eq!(unsorted, uuids);
+    round_trip(&uuids, serde_json::to_vec, |b| serde_json::from_slice(b));
+
+    // In binary formats, v7 UUIDs are delta-encoded: here, b takes up 16
+    // bytes (a 6-byte timestamp header and 10 bytes), c takes up 11, and a,
+    // a v4 UUID, takes up 17.
+    let encoded = postcard::to_allocvec(&uuids).unwrap();
+    assert_eq!(encoded.len(), 1 + 16 + 11 + 17);
+    round_trip(&uuids, postcard::to_allocvec, |b| postcard::from_bytes(b));
+    round_trip(&uuids, bincode1::serialize, |b| bincode1::deserialize(b));
+}
+
+#[test]
+fn test_typed_uuid_str() {
+    let uuid = TypedUuid::<MyKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+
+    // TypedUuidStr is serialized as a string in all formats, in the same way
+    // as the `string` and `prefixed` helpers.
+    let s = uuid.to_str_buf();
+    assert_eq!(
+        serde_json::to_string(&s).unwrap(),
+        r#""dffc3068-1cd6-47d5-b2f3-636b41b07084""#
+    );
+    let prefixed = uuid.to_prefixed_str_buf().unwrap();
+    assert_eq!(
+        serde_json::to_string(&prefixed).unwrap(),
+        r#""my_kind:dffc3068-1cd6-47d5-b2f3-636b41b07084""#
+    );
+    for value in [s, prefixed] {
+        round_trip(&value, serde_json::to_vec, |b| serde_json::from_slice(b));
+        round_trip(&value, bincode1::serialize, |b| bincode1::deserialize(b));
+        round_trip(&value, postcard::to_allocvec, |b| postcard::from_bytes(b));
+    }
+
+    let error = serde_json::from_str::<TypedUuidStr>(r#""my_kind:bogus""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "error parsing UUID string: UUID must be in hyphenated form at line 1 column 15"
+    );
+}
@@ -0,0 +1,71 @@
+//! Tests for recording typed UUIDs in tracing events.
+
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    Event, Metadata,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    subscriber::Interest,
+};
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+/// A minimal subscriber that records the fields of each event.
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<(String, String)>>>);
+
+impl Visit for Capture {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .lock()
+            .unwrap()
+            .push((field.name().to_owned(), format!("{value:?}")));
+    }
+}
+
+impl tracing::Subscriber for Capture {
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+        Interest::always()
+    }
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+    fn enter(&self, _: &Id) {}
+    fn exit(&self, _: &Id) {}
+}
+
+// With `--cfg tracing_unstable` and `valuable01`, the UUID is recorded as a
+// structured value instead.
+#[test]
+#[cfg(not(tracing_unstable))]
+fn test_tracing_value() {
+    let uuid = UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    let capture = Capture::default();
+    tracing::subscriber::with_default(capture.clone(), || {
+        tracing::info!(id = uuid.as_tracing_value(), "event");
+    });
+    let fields = capture.0.lock().unwrap();
+    assert!(
+        fields.contains(&(
+            "id".to_owned(),
+            "user:dffc3068-1cd6-47d5-b2f3-636b41b07084".to_owned()
+        )),
+        "unexpected fields: {fields:?}"
+    );
+}
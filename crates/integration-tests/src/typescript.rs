@@ -0,0 +1,75 @@
+//! TypeScript declaration tests for newtype-uuid, via ts-rs.
+
+use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use ts_rs::TS;
+
+// The ts_rs11 setting isn't used here, since the tests it generates would
+// write declarations into the source tree.
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+        Project = { alias = ProjUuid },
+    }
+}
+
+/// A kind without an alias, so its type name is derived from the tag.
+#[derive(Debug)]
+enum LegacyKind {}
+
+impl TypedUuidKind for LegacyKind {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("legacy-item");
+        TAG
+    }
+}
+
+#[derive(TS)]
+#[expect(dead_code)]
+struct Membership {
+    user_id: UserUuid,
+    project_ids: Vec<ProjUuid>,
+    legacy_id: Option<TypedUuid<LegacyKind>>,
+}
+
+#[test]
+fn test_typescript_snapshot() {
+    let output = Membership::export_to_string().unwrap();
+    expectorate::assert_contents("outputs/typed-uuid-typescript.ts", &output);
+}
+
+#[test]
+fn test_branded_declarations() {
+    assert_eq!(UserUuid::name(), "UserUuid");
+    assert_eq!(
+        UserUuid::decl(),
+        r#"type UserUuid = string & { readonly __kind: "user" };"#
+    );
+    assert_eq!(
+        ProjUuid::inline(),
+        r#"string & { readonly __kind: "project" }"#
+    );
+    assert_eq!(
+        TypedUuid::<LegacyKind>::decl(),
+        r#"type LegacyItemUuid = string & { readonly __kind: "legacy-item" };"#
+    );
+    assert!(
+        UserUuid::export_to_string()
+            .unwrap()
+            .ends_with("export type UserUuid = string & { readonly __kind: \"user\" };\n"),
+    );
+    assert_eq!(
+        TypedUuid::<LegacyKind>::output_path().unwrap(),
+        std::path::Path::new("LegacyItemUuid.ts"),
+    );
+}
+
+#[test]
+fn test_dependencies() {
+    let mut deps: Vec<_> = Membership::dependencies()
+        .into_iter()
+        .map(|dep| dep.ts_name)
+        .collect();
+    deps.sort();
+    assert_eq!(deps, ["LegacyItemUuid", "ProjUuid", "UserUuid"]);
+}
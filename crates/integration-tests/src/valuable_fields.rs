@@ -0,0 +1,49 @@
+//! Tests for inspecting typed UUIDs with valuable.
+
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use valuable::{NamedValues, Valuable, Value, Visit};
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+#[test]
+fn test_valuable_fields() {
+    #[derive(Default)]
+    struct Collect(Vec<(String, String)>);
+
+    impl Visit for Collect {
+        fn visit_value(&mut self, value: Value<'_>) {
+            let Value::Structable(value) = value else {
+                panic!("expected a structable value, found {value:?}");
+            };
+            value.visit(self);
+        }
+
+        fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+            for (field, value) in named_values {
+                let Value::String(value) = value else {
+                    panic!("unexpected value for {}: {value:?}", field.name());
+                };
+                self.0.push((field.name().to_owned(), (*value).to_owned()));
+            }
+        }
+    }
+
+    let uuid = UserUuid::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    let mut collect = Collect::default();
+    valuable::visit(&uuid, &mut collect);
+    assert_eq!(
+        collect.0,
+        [
+            ("kind".to_owned(), "user".to_owned()),
+            (
+                "uuid".to_owned(),
+                "dffc3068-1cd6-47d5-b2f3-636b41b07084".to_owned()
+            ),
+        ]
+    );
+    assert!(matches!(uuid.as_value(), Value::Structable(_)));
+}
@@ -0,0 +1,23 @@
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        Organization = {
+            constants = {
+                // Not a valid UUID.
+                ROOT = "001de000-05e4-4000-8000-00000000000",
+                // Not a string literal.
+                SYSTEM = 1234,
+            },
+        },
+        User = {
+            constants = {
+                SYSTEM = "001de000-05e4-4000-8000-000000000001",
+                // Duplicate constant name.
+                SYSTEM = "001de000-05e4-4000-8000-000000000002",
+            },
+        },
+    }
+}
+
+fn main() {}
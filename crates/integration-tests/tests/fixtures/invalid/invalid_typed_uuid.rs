@@ -0,0 +1,23 @@
+use newtype_uuid_macros::{impl_typed_uuid_kinds, typed_uuid};
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+// One character short.
+const SHORT: UserUuid = typed_uuid!(UserKind, "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a");
+// Not hexadecimal.
+const INVALID: UserUuid = typed_uuid!(UserKind, "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7ag");
+// A v4 UUID, not a v7 UUID.
+const WRONG_VERSION: UserUuid = typed_uuid!(
+    UserKind,
+    "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0",
+    version = 7,
+);
+const BAD_VERSION: UserUuid = typed_uuid!(UserKind, "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0", version = 9);
+const UNKNOWN_OPTION: UserUuid = typed_uuid!(UserKind, "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0", variant = 1);
+const NOT_A_STRING: UserUuid = typed_uuid!(UserKind, 0x6f2b94fc);
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserKind {}
+impl ::newtype_uuid::TypedUuidKind for UserKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "user",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(UserUuid))
+    }
+}
+#[allow(unused)]
+pub type UserUuid = ::newtype_uuid::TypedUuid<UserKind>;
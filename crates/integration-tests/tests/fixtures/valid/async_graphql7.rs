@@ -0,0 +1,30 @@
+//! Test GraphQL support via async-graphql 7, gated on a feature.
+
+use newtype_uuid::TypedUuidKind;
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    settings = {
+        async_graphql7 = {
+            attrs = [#[cfg(feature = "internal-async-graphql7-tests")]],
+        },
+    },
+    kinds = {
+        User = {},
+        Organization = { alias = OrgUuid },
+    }
+}
+
+fn main() {
+    assert_eq!(UserKind::tag().as_str(), "user");
+    assert_eq!(OrganizationKind::tag().as_str(), "organization");
+
+    #[cfg(feature = "internal-async-graphql7-tests")]
+    {
+        use async_graphql::{Description, InputType};
+
+        assert_eq!(UserKind::description(), "A typed UUID of kind `user`.");
+        assert_eq!(<UserUuid as InputType>::type_name(), "UserUuid");
+        assert_eq!(<OrgUuid as InputType>::type_name(), "OrgUuid");
+    }
+}
@@ -0,0 +1,53 @@
+use newtype_uuid::TypedUuidKind;
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    settings = {
+        schemars08 = {
+            attrs = [#[cfg(feature = "internal-schemars08-tests")]],
+            rust_type = {
+                crate = "my-service",
+                version = "1.0.0",
+                path = "my_service::types",
+            },
+        },
+    },
+    kinds = {
+        Organization = {
+            constants = {
+                ROOT = "001de000-05e4-4000-8000-000000000000",
+            },
+        },
+        User = {
+            alias = AccountUuid,
+            constants = {
+                SYSTEM = "001de000-05e4-4000-8000-000000000001",
+                // Uppercase and non-hyphenated forms are accepted too.
+                ANONYMOUS = "001DE00005E440008000000000000002",
+            },
+        },
+        Project = {},
+    }
+}
+
+fn main() {
+    assert_eq!(OrganizationKind::tag().as_str(), "organization");
+
+    // Constants are available through the generated extension traits.
+    assert_eq!(
+        OrganizationUuid::ROOT.to_string(),
+        "001de000-05e4-4000-8000-000000000000",
+    );
+    assert_eq!(
+        AccountUuid::SYSTEM.to_string(),
+        "001de000-05e4-4000-8000-000000000001",
+    );
+    assert_eq!(
+        AccountUuid::ANONYMOUS.to_string(),
+        "001de000-05e4-4000-8000-000000000002",
+    );
+
+    // Constants can be used in const contexts.
+    const ROOT: OrganizationUuid = OrganizationUuid::ROOT;
+    assert_eq!(ROOT, OrganizationUuid::ROOT);
+}
@@ -0,0 +1,21 @@
+//! Test defmt support via defmt 1.x, gated on a feature.
+
+use newtype_uuid::TypedUuidKind;
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    settings = {
+        defmt1 = {
+            attrs = [#[cfg(feature = "internal-defmt1-tests")]],
+        },
+    },
+    kinds = {
+        User = {},
+        Organization = { tag = "org" },
+    }
+}
+
+fn main() {
+    assert_eq!(UserKind::tag().as_str(), "user");
+    assert_eq!(OrganizationKind::tag().as_str(), "org");
+}
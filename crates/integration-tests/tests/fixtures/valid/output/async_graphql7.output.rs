@@ -0,0 +1,44 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserKind {}
+impl ::newtype_uuid::TypedUuidKind for UserKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "user",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(UserUuid))
+    }
+}
+#[cfg(feature = "internal-async-graphql7-tests")]
+impl ::newtype_uuid::macro_support::async_graphql7::Description for UserKind {
+    fn description() -> &'static str {
+        "A typed UUID of kind `user`."
+    }
+}
+#[allow(unused)]
+pub type UserUuid = ::newtype_uuid::TypedUuid<UserKind>;
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrganizationKind {}
+impl ::newtype_uuid::TypedUuidKind for OrganizationKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "organization",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(OrgUuid))
+    }
+}
+#[cfg(feature = "internal-async-graphql7-tests")]
+impl ::newtype_uuid::macro_support::async_graphql7::Description for OrganizationKind {
+    fn description() -> &'static str {
+        "A typed UUID of kind `organization`."
+    }
+}
+#[allow(unused)]
+pub type OrgUuid = ::newtype_uuid::TypedUuid<OrganizationKind>;
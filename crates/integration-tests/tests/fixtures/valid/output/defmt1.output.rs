@@ -0,0 +1,48 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserKind {}
+impl ::newtype_uuid::TypedUuidKind for UserKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "user",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(UserUuid))
+    }
+}
+#[cfg(feature = "internal-defmt1-tests")]
+impl ::newtype_uuid::DefmtKind for UserKind {
+    #[inline]
+    fn defmt_tag() -> ::newtype_uuid::macro_support::defmt1::Str {
+        use ::newtype_uuid::macro_support::defmt1 as defmt;
+        defmt::intern!("user")
+    }
+}
+#[allow(unused)]
+pub type UserUuid = ::newtype_uuid::TypedUuid<UserKind>;
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrganizationKind {}
+impl ::newtype_uuid::TypedUuidKind for OrganizationKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "org",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(OrganizationUuid))
+    }
+}
+#[cfg(feature = "internal-defmt1-tests")]
+impl ::newtype_uuid::DefmtKind for OrganizationKind {
+    #[inline]
+    fn defmt_tag() -> ::newtype_uuid::macro_support::defmt1::Str {
+        use ::newtype_uuid::macro_support::defmt1 as defmt;
+        defmt::intern!("org")
+    }
+}
+#[allow(unused)]
+pub type OrganizationUuid = ::newtype_uuid::TypedUuid<OrganizationKind>;
@@ -0,0 +1,46 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserKind {}
+impl ::newtype_uuid::TypedUuidKind for UserKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "user",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(UserUuid))
+    }
+}
+#[allow(unused)]
+pub type UserUuid = ::newtype_uuid::TypedUuid<UserKind>;
+#[cfg(feature = "internal-ts-rs11-tests")]
+#[cfg(test)]
+#[test]
+fn export_bindings_useruuid() {
+    <UserUuid as ::newtype_uuid::macro_support::ts_rs11::TS>::export_all()
+        .expect("could not export type");
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrganizationKind {}
+impl ::newtype_uuid::TypedUuidKind for OrganizationKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "organization",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(OrgUuid))
+    }
+}
+#[allow(unused)]
+pub type OrgUuid = ::newtype_uuid::TypedUuid<OrganizationKind>;
+#[cfg(feature = "internal-ts-rs11-tests")]
+#[cfg(test)]
+#[test]
+fn export_bindings_orguuid() {
+    <OrgUuid as ::newtype_uuid::macro_support::ts_rs11::TS>::export_all()
+        .expect("could not export type");
+}
@@ -0,0 +1,90 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserKind {}
+impl ::newtype_uuid::TypedUuidKind for UserKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "user",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(UserUuid))
+    }
+}
+#[cfg(feature = "internal-utoipa5-tests")]
+impl ::newtype_uuid::macro_support::utoipa5::PartialSchema for UserKind {
+    fn schema() -> ::newtype_uuid::macro_support::utoipa5::openapi::RefOr<
+        ::newtype_uuid::macro_support::utoipa5::openapi::schema::Schema,
+    > {
+        use ::newtype_uuid::macro_support::utoipa5::openapi::{
+            extensions::ExtensionsBuilder, schema::{ObjectBuilder, SchemaType},
+        };
+        let rust_type = ::newtype_uuid::macro_support::serde_json::json!(
+            { "crate" : "my-service", "version" : "1.0.0", "path" :
+            "my_service::types::UserKind", }
+        );
+        ObjectBuilder::new()
+            .schema_type(SchemaType::AnyValue)
+            .extensions(
+                ::std::option::Option::Some(
+                    ExtensionsBuilder::new().add("x-rust-type", rust_type).build(),
+                ),
+            )
+            .build()
+            .into()
+    }
+}
+#[cfg(feature = "internal-utoipa5-tests")]
+impl ::newtype_uuid::macro_support::utoipa5::ToSchema for UserKind {
+    fn name() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed("UserKind")
+    }
+}
+#[allow(unused)]
+pub type UserUuid = ::newtype_uuid::TypedUuid<UserKind>;
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrganizationKind {}
+impl ::newtype_uuid::TypedUuidKind for OrganizationKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "organization",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(OrgUuid))
+    }
+}
+#[cfg(feature = "internal-utoipa5-tests")]
+impl ::newtype_uuid::macro_support::utoipa5::PartialSchema for OrganizationKind {
+    fn schema() -> ::newtype_uuid::macro_support::utoipa5::openapi::RefOr<
+        ::newtype_uuid::macro_support::utoipa5::openapi::schema::Schema,
+    > {
+        use ::newtype_uuid::macro_support::utoipa5::openapi::{
+            extensions::ExtensionsBuilder, schema::{ObjectBuilder, SchemaType},
+        };
+        let rust_type = ::newtype_uuid::macro_support::serde_json::json!(
+            { "crate" : "my-service", "version" : "1.0.0", "path" :
+            "my_service::types::OrganizationKind", }
+        );
+        ObjectBuilder::new()
+            .schema_type(SchemaType::AnyValue)
+            .extensions(
+                ::std::option::Option::Some(
+                    ExtensionsBuilder::new().add("x-rust-type", rust_type).build(),
+                ),
+            )
+            .build()
+            .into()
+    }
+}
+#[cfg(feature = "internal-utoipa5-tests")]
+impl ::newtype_uuid::macro_support::utoipa5::ToSchema for OrganizationKind {
+    fn name() -> ::std::borrow::Cow<'static, str> {
+        ::std::borrow::Cow::Borrowed("OrganizationKind")
+    }
+}
+#[allow(unused)]
+pub type OrgUuid = ::newtype_uuid::TypedUuid<OrganizationKind>;
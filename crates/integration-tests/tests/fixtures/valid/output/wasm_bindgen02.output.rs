@@ -0,0 +1,46 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserKind {}
+impl ::newtype_uuid::TypedUuidKind for UserKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "user",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(UserUuid))
+    }
+}
+#[allow(unused)]
+pub type UserUuid = ::newtype_uuid::TypedUuid<UserKind>;
+#[cfg(feature = "internal-wasm-bindgen02-tests")]
+const _: () = {
+    #[allow(unused_imports)]
+    use ::newtype_uuid::macro_support::wasm_bindgen02 as wasm_bindgen;
+    #[wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
+    const TS_DECL: &str = "export type UserUuid = string & { readonly __kind: \"user\" };";
+};
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrganizationKind {}
+impl ::newtype_uuid::TypedUuidKind for OrganizationKind {
+    #[inline]
+    fn tag() -> ::newtype_uuid::TypedUuidTag {
+        const TAG: ::newtype_uuid::TypedUuidTag = ::newtype_uuid::TypedUuidTag::new(
+            "organization",
+        );
+        TAG
+    }
+    fn alias() -> Option<&'static str> {
+        Some(stringify!(OrgUuid))
+    }
+}
+#[allow(unused)]
+pub type OrgUuid = ::newtype_uuid::TypedUuid<OrganizationKind>;
+#[cfg(feature = "internal-wasm-bindgen02-tests")]
+const _: () = {
+    #[allow(unused_imports)]
+    use ::newtype_uuid::macro_support::wasm_bindgen02 as wasm_bindgen;
+    #[wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
+    const TS_DECL: &str = "export type OrgUuid = string & { readonly __kind: \"organization\" };";
+};
@@ -0,0 +1,32 @@
+//! Test TypeScript exports via ts-rs 11, gated on a feature.
+
+use newtype_uuid::TypedUuidKind;
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    settings = {
+        ts_rs11 = {
+            attrs = [#[cfg(feature = "internal-ts-rs11-tests")]],
+        },
+    },
+    kinds = {
+        User = {},
+        Organization = { alias = OrgUuid },
+    }
+}
+
+fn main() {
+    assert_eq!(UserKind::tag().as_str(), "user");
+    assert_eq!(OrganizationKind::tag().as_str(), "organization");
+
+    #[cfg(feature = "internal-ts-rs11-tests")]
+    {
+        use ts_rs::TS;
+
+        assert_eq!(UserUuid::name(), "UserUuid");
+        assert_eq!(
+            OrgUuid::decl(),
+            r#"type OrgUuid = string & { readonly __kind: "organization" };"#
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use newtype_uuid::TypedUuid;
+use newtype_uuid_macros::{impl_typed_uuid_kinds, typed_uuid};
+
+impl_typed_uuid_kinds! {
+    kinds = {
+        User = {},
+    }
+}
+
+// Typed UUID literals can be used in const and static items.
+const ADMIN: UserUuid = typed_uuid!(UserKind, "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0");
+static SYSTEM: TypedUuid<UserKind> = typed_uuid!(
+    UserKind,
+    "01890a5d-ac96-774b-bcce-b302099a8057",
+    version = 7,
+    newtype_uuid_crate = newtype_uuid,
+);
+
+// Any format accepted by `Uuid::parse_str` works.
+const SIMPLE: UserUuid = typed_uuid!(UserKind, "6F2B94FC31C44A0E9B1E2C4BD2E1F7A0", version = 4);
+const PARSED: UserUuid = TypedUuid::parse_str_const("6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0");
+
+fn main() {
+    assert_eq!(ADMIN, SIMPLE);
+    assert_eq!(ADMIN, PARSED);
+    assert_eq!(SYSTEM.get_version_num(), 7);
+}
@@ -0,0 +1,35 @@
+//! Test OpenAPI support via utoipa 5, gated on a feature.
+
+use newtype_uuid::TypedUuidKind;
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    settings = {
+        utoipa5 = {
+            attrs = [#[cfg(feature = "internal-utoipa5-tests")]],
+            rust_type = {
+                crate = "my-service",
+                version = "1.0.0",
+                path = "my_service::types",
+            },
+        },
+    },
+    kinds = {
+        User = {},
+        Organization = { alias = OrgUuid },
+    }
+}
+
+fn main() {
+    assert_eq!(UserKind::tag().as_str(), "user");
+    assert_eq!(OrganizationKind::tag().as_str(), "organization");
+
+    #[cfg(feature = "internal-utoipa5-tests")]
+    {
+        use utoipa::ToSchema;
+
+        assert_eq!(UserKind::name(), "UserKind");
+        assert_eq!(UserUuid::name(), "UserUuid");
+        assert_eq!(OrgUuid::name(), "OrgUuid");
+    }
+}
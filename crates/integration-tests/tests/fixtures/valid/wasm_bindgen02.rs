@@ -0,0 +1,21 @@
+//! Test TypeScript declarations via wasm-bindgen 0.2, gated on a feature.
+
+use newtype_uuid::TypedUuidKind;
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+
+impl_typed_uuid_kinds! {
+    settings = {
+        wasm_bindgen02 = {
+            attrs = [#[cfg(feature = "internal-wasm-bindgen02-tests")]],
+        },
+    },
+    kinds = {
+        User = {},
+        Organization = { alias = OrgUuid },
+    }
+}
+
+fn main() {
+    assert_eq!(UserKind::tag().as_str(), "user");
+    assert_eq!(OrganizationKind::tag().as_str(), "organization");
+}
@@ -88,6 +88,7 @@ pub fn impl_typed_uuid_kinds(input: TokenStream) -> ImplKindsOutput {
             generate_schemars_impl(
                 &kind_name_ident,
                 &kind_name_ident.to_string(),
+                &config.constants,
                 schemars_settings,
                 newtype_uuid_crate,
             )
@@ -95,6 +96,72 @@ pub fn impl_typed_uuid_kinds(input: TokenStream) -> ImplKindsOutput {
             quote! {}
         };
 
+        // Generate ToSchema implementation if utoipa5 settings are provided
+        let utoipa_impl = if let Some(utoipa_settings) = &params.settings.utoipa5 {
+            generate_utoipa_impl(
+                &kind_name_ident,
+                &kind_name_ident.to_string(),
+                utoipa_settings,
+                newtype_uuid_crate,
+            )
+        } else {
+            quote! {}
+        };
+
+        // Generate Description implementation if async_graphql7 settings are provided
+        let async_graphql_impl =
+            if let Some(async_graphql_settings) = &params.settings.async_graphql7 {
+                generate_async_graphql_impl(
+                    &kind_name_ident,
+                    &tag_name,
+                    async_graphql_settings,
+                    newtype_uuid_crate,
+                )
+            } else {
+                quote! {}
+            };
+
+        // Generate a TypeScript export test if ts_rs11 settings are provided
+        let ts_rs_impl = if let Some(ts_rs_settings) = &params.settings.ts_rs11 {
+            generate_ts_rs_impl(&alias_ident, ts_rs_settings, newtype_uuid_crate)
+        } else {
+            quote! {}
+        };
+
+        // Generate DefmtKind implementation if defmt1 settings are provided
+        let defmt_impl = if let Some(defmt_settings) = &params.settings.defmt1 {
+            generate_defmt_impl(
+                &kind_name_ident,
+                &tag_name,
+                defmt_settings,
+                newtype_uuid_crate,
+            )
+        } else {
+            quote! {}
+        };
+
+        // Generate a TypeScript custom section if wasm_bindgen02 settings are provided
+        let wasm_bindgen_impl = if let Some(wasm_bindgen_settings) = &params.settings.wasm_bindgen02
+        {
+            generate_wasm_bindgen_impl(
+                &alias_ident,
+                &tag_name,
+                wasm_bindgen_settings,
+                newtype_uuid_crate,
+            )
+        } else {
+            quote! {}
+        };
+
+        // Generate an extension trait with associated constants if any are
+        // provided
+        let constants_impl = generate_constants_impl(
+            &kind_name_ident,
+            &alias_ident,
+            &config.constants,
+            newtype_uuid_crate,
+        );
+
         let expanded = quote_spanned! {root_ident.span() =>
             #[derive(Clone, Copy, Debug, PartialEq, Eq)]
             #(#attrs)*
@@ -115,8 +182,20 @@ pub fn impl_typed_uuid_kinds(input: TokenStream) -> ImplKindsOutput {
 
             #schemars_impl
 
+            #utoipa_impl
+
+            #async_graphql_impl
+
+            #defmt_impl
+
             #[allow(unused)]
             pub type #alias_ident = ::#newtype_uuid_crate::TypedUuid<#kind_name_ident>;
+
+            #constants_impl
+
+            #ts_rs_impl
+
+            #wasm_bindgen_impl
         };
 
         out.extend(expanded);
@@ -279,6 +358,26 @@ struct GlobalSettings {
     /// Schemars configuration.
     #[serde(default)]
     schemars08: Option<SchemarsSettings>,
+
+    /// Utoipa configuration.
+    #[serde(default)]
+    utoipa5: Option<UtoipaSettings>,
+
+    /// async-graphql configuration.
+    #[serde(default)]
+    async_graphql7: Option<AsyncGraphqlSettings>,
+
+    /// ts-rs configuration.
+    #[serde(default)]
+    ts_rs11: Option<TsRsSettings>,
+
+    /// defmt configuration.
+    #[serde(default)]
+    defmt1: Option<DefmtSettings>,
+
+    /// wasm-bindgen configuration.
+    #[serde(default)]
+    wasm_bindgen02: Option<WasmBindgenSettings>,
 }
 
 /// Settings for schemars08 integration.
@@ -290,6 +389,47 @@ struct SchemarsSettings {
     rust_type: RustTypeSettings,
 }
 
+/// Settings for utoipa5 integration.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UtoipaSettings {
+    #[serde(default)]
+    attrs: Vec<TokenStreamWrapper>,
+    rust_type: RustTypeSettings,
+}
+
+/// Settings for async-graphql7 integration.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AsyncGraphqlSettings {
+    #[serde(default)]
+    attrs: Vec<TokenStreamWrapper>,
+}
+
+/// Settings for ts-rs11 integration.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TsRsSettings {
+    #[serde(default)]
+    attrs: Vec<TokenStreamWrapper>,
+}
+
+/// Settings for defmt1 integration.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DefmtSettings {
+    #[serde(default)]
+    attrs: Vec<TokenStreamWrapper>,
+}
+
+/// Settings for wasm-bindgen02 integration.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WasmBindgenSettings {
+    #[serde(default)]
+    attrs: Vec<TokenStreamWrapper>,
+}
+
 /// Settings for the x-rust-type extension.
 #[derive(Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -318,6 +458,10 @@ struct KindConfig {
     /// Attributes to apply to generated types (e.g. derives).
     #[serde(default)]
     attrs: Option<Vec<TokenStreamWrapper>>,
+
+    /// Well-known UUIDs for this kind, as a map of names to string literals.
+    #[serde(default)]
+    constants: Option<OrderedMap<TokenStreamWrapper, TokenStreamWrapper>>,
 }
 
 impl KindConfig {
@@ -355,6 +499,44 @@ impl KindConfig {
             },
             None => Ok(None),
         };
+        // Parse each constant as an Ident mapped to a LitStr containing a
+        // valid UUID.
+        let mut constants = Vec::new();
+        for (name, value) in self.constants.unwrap_or_default() {
+            let name = match syn::parse2::<syn::Ident>(name.into_inner()) {
+                Ok(ident) => ident,
+                Err(error) => {
+                    errors.push_critical(error);
+                    continue;
+                }
+            };
+            let value = match syn::parse2::<syn::LitStr>(value.into_inner()) {
+                Ok(lit_str) => lit_str,
+                Err(error) => {
+                    errors.push_critical(error);
+                    continue;
+                }
+            };
+            if constants
+                .iter()
+                .any(|constant: &KindConstant| constant.name == name)
+            {
+                errors.push_critical(syn::Error::new(
+                    name.span(),
+                    format!("constant `{name}` specified more than once"),
+                ));
+                continue;
+            }
+            match uuid::Uuid::parse_str(&value.value()) {
+                Ok(uuid) => constants.push(KindConstant { name, value, uuid }),
+                Err(error) => {
+                    errors.push_critical(syn::Error::new(
+                        value.span(),
+                        format!("invalid UUID `{}` for `{name}`: {error}", value.value()),
+                    ));
+                }
+            }
+        }
 
         if errors.has_critical_errors() {
             None
@@ -365,6 +547,7 @@ impl KindConfig {
                 alias: alias.expect("alias is valid"),
                 tag: tag.expect("tag is valid"),
                 attrs: self.attrs,
+                constants,
             })
         }
     }
@@ -375,12 +558,21 @@ struct ParsedKindConfig {
     alias: Option<syn::Ident>,
     tag: Option<syn::LitStr>,
     attrs: Option<Vec<TokenStreamWrapper>>,
+    constants: Vec<KindConstant>,
+}
+
+/// A well-known UUID for a kind, validated at expansion time.
+struct KindConstant {
+    name: syn::Ident,
+    value: syn::LitStr,
+    uuid: uuid::Uuid,
 }
 
 /// Generate a hand-written JsonSchema implementation for a kind.
 fn generate_schemars_impl(
     kind_name_ident: &syn::Ident,
     kind_name: &str,
+    constants: &[KindConstant],
     schemars_settings: &SchemarsSettings,
     newtype_uuid_crate: &syn::Ident,
 ) -> proc_macro2::TokenStream {
@@ -392,6 +584,23 @@ fn generate_schemars_impl(
     // Construct the full path for this specific kind.
     let full_path = format!("{}::{}", path_prefix, kind_name_ident);
 
+    // Constants are exported as examples, which TypedUuid's schema lifts up.
+    let metadata = if constants.is_empty() {
+        quote! {}
+    } else {
+        let examples = constants
+            .iter()
+            .map(|constant| constant.uuid.hyphenated().to_string());
+        quote! {
+            schema.metadata = ::std::option::Option::Some(Box::new(Metadata {
+                examples: vec![
+                    #(::#newtype_uuid_crate::macro_support::serde_json::json!(#examples),)*
+                ],
+                ..::std::default::Default::default()
+            }));
+        }
+    };
+
     quote! {
         #(#attrs)*
         impl ::#newtype_uuid_crate::macro_support::schemars08::JsonSchema for #kind_name_ident {
@@ -426,8 +635,199 @@ fn generate_schemars_impl(
                 extensions.insert("x-rust-type".to_string(), rust_type);
                 schema.extensions = extensions;
 
+                #metadata
+
                 Schema::Object(schema)
             }
         }
     }
 }
+
+/// Generate an extension trait providing associated constants for a kind's
+/// well-known UUIDs.
+fn generate_constants_impl(
+    kind_name_ident: &syn::Ident,
+    alias_ident: &syn::Ident,
+    constants: &[KindConstant],
+    newtype_uuid_crate: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    if constants.is_empty() {
+        return quote! {};
+    }
+
+    let trait_ident = format_ident!("{}Constants", alias_ident);
+    let trait_doc = format!("Well-known [`{alias_ident}`] values.");
+    let docs = constants
+        .iter()
+        .map(|constant| format!("`{}`", constant.uuid.hyphenated()));
+    let names = constants.iter().map(|constant| &constant.name);
+    let impl_names = names.clone();
+    let values = constants.iter().map(|constant| {
+        let value = proc_macro2::Literal::u128_unsuffixed(constant.uuid.as_u128());
+        quote_spanned! {constant.value.span()=>
+            ::#newtype_uuid_crate::TypedUuid::<#kind_name_ident>::from_u128(#value)
+        }
+    });
+
+    quote! {
+        #[doc = #trait_doc]
+        pub trait #trait_ident {
+            #(
+                #[doc = #docs]
+                const #names: ::#newtype_uuid_crate::TypedUuid<#kind_name_ident>;
+            )*
+        }
+
+        impl #trait_ident for ::#newtype_uuid_crate::TypedUuid<#kind_name_ident> {
+            #(
+                const #impl_names: ::#newtype_uuid_crate::TypedUuid<#kind_name_ident> = #values;
+            )*
+        }
+    }
+}
+
+/// Generate hand-written PartialSchema and ToSchema implementations for a kind.
+fn generate_utoipa_impl(
+    kind_name_ident: &syn::Ident,
+    kind_name: &str,
+    utoipa_settings: &UtoipaSettings,
+    newtype_uuid_crate: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let attrs = utoipa_settings.attrs.iter().map(|attrs| &**attrs);
+    let attrs2 = attrs.clone();
+    let crate_name = &utoipa_settings.rust_type.crate_name;
+    let version = &utoipa_settings.rust_type.version;
+    let path_prefix = &utoipa_settings.rust_type.path;
+
+    // Construct the full path for this specific kind.
+    let full_path = format!("{}::{}", path_prefix, kind_name_ident);
+
+    quote! {
+        #(#attrs)*
+        impl ::#newtype_uuid_crate::macro_support::utoipa5::PartialSchema for #kind_name_ident {
+            fn schema() -> ::#newtype_uuid_crate::macro_support::utoipa5::openapi::RefOr<
+                ::#newtype_uuid_crate::macro_support::utoipa5::openapi::schema::Schema,
+            > {
+                use ::#newtype_uuid_crate::macro_support::utoipa5::openapi::{
+                    extensions::ExtensionsBuilder,
+                    schema::{ObjectBuilder, SchemaType},
+                };
+
+                // Kinds are never instantiated, so the schema only carries the
+                // x-rust-type extension.
+                let rust_type = ::#newtype_uuid_crate::macro_support::serde_json::json!({
+                    "crate": #crate_name,
+                    "version": #version,
+                    "path": #full_path,
+                });
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::AnyValue)
+                    .extensions(::std::option::Option::Some(
+                        ExtensionsBuilder::new().add("x-rust-type", rust_type).build(),
+                    ))
+                    .build()
+                    .into()
+            }
+        }
+
+        #(#attrs2)*
+        impl ::#newtype_uuid_crate::macro_support::utoipa5::ToSchema for #kind_name_ident {
+            fn name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(#kind_name)
+            }
+        }
+    }
+}
+
+/// Generate a Description implementation for a kind.
+///
+/// With it, `TypedUuid<Kind>` is registered as a GraphQL scalar.
+fn generate_async_graphql_impl(
+    kind_name_ident: &syn::Ident,
+    tag_name: &str,
+    async_graphql_settings: &AsyncGraphqlSettings,
+    newtype_uuid_crate: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let attrs = async_graphql_settings.attrs.iter().map(|attrs| &**attrs);
+    let description = format!("A typed UUID of kind `{tag_name}`.");
+
+    quote! {
+        #(#attrs)*
+        impl ::#newtype_uuid_crate::macro_support::async_graphql7::Description for #kind_name_ident {
+            fn description() -> &'static str {
+                #description
+            }
+        }
+    }
+}
+
+/// Generate a test that exports the TypeScript declaration for a kind's alias,
+/// mirroring `#[ts(export)]`.
+fn generate_ts_rs_impl(
+    alias_ident: &syn::Ident,
+    ts_rs_settings: &TsRsSettings,
+    newtype_uuid_crate: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let attrs = ts_rs_settings.attrs.iter().map(|attrs| &**attrs);
+    let test_fn = format_ident!("export_bindings_{}", alias_ident.to_string().to_lowercase());
+
+    quote! {
+        #(#attrs)*
+        #[cfg(test)]
+        #[test]
+        fn #test_fn() {
+            <#alias_ident as ::#newtype_uuid_crate::macro_support::ts_rs11::TS>::export_all()
+                .expect("could not export type");
+        }
+    }
+}
+
+/// Generate a DefmtKind implementation for a kind, interning its tag.
+fn generate_defmt_impl(
+    kind_name_ident: &syn::Ident,
+    tag_name: &str,
+    defmt_settings: &DefmtSettings,
+    newtype_uuid_crate: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let attrs = defmt_settings.attrs.iter().map(|attrs| &**attrs);
+
+    // `defmt::intern!` expands to paths starting with `defmt::`, which must
+    // resolve even if the invoking crate doesn't depend on defmt directly.
+    quote! {
+        #(#attrs)*
+        impl ::#newtype_uuid_crate::DefmtKind for #kind_name_ident {
+            #[inline]
+            fn defmt_tag() -> ::#newtype_uuid_crate::macro_support::defmt1::Str {
+                use ::#newtype_uuid_crate::macro_support::defmt1 as defmt;
+                defmt::intern!(#tag_name)
+            }
+        }
+    }
+}
+
+/// Generate a wasm-bindgen TypeScript custom section for a kind, declaring
+/// its type alias as a branded string.
+fn generate_wasm_bindgen_impl(
+    alias_ident: &syn::Ident,
+    tag_name: &str,
+    wasm_bindgen_settings: &WasmBindgenSettings,
+    newtype_uuid_crate: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let attrs = wasm_bindgen_settings.attrs.iter().map(|attrs| &**attrs);
+    // This matches the declaration generated by ts-rs11.
+    let decl =
+        format!("export type {alias_ident} = string & {{ readonly __kind: \"{tag_name}\" }};");
+
+    // wasm-bindgen's generated code refers to `wasm_bindgen` by a relative
+    // path, so bring the re-export into scope under that name.
+    quote! {
+        #(#attrs)*
+        const _: () = {
+            #[allow(unused_imports)]
+            use ::#newtype_uuid_crate::macro_support::wasm_bindgen02 as wasm_bindgen;
+
+            #[wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
+            const TS_DECL: &str = #decl;
+        };
+    }
+}
@@ -4,6 +4,8 @@
 //! [`newtype-uuid`](https://docs.rs/newtype-uuid) instances.
 //!
 //! For more information, see the documentation for [`impl_typed_uuid_kinds!`].
+//! The [`typed_uuid!`] macro is also provided, for writing typed UUID literals
+//! that are validated at compile time.
 //!
 //! # Examples
 //!
@@ -38,6 +40,7 @@
 #![warn(missing_docs)]
 
 mod internals;
+mod literal;
 
 use proc_macro::TokenStream;
 use quote::ToTokens;
@@ -47,7 +50,9 @@ use quote::ToTokens;
 /// This macro generates types that implement `TypedUuidKind` and corresponding
 /// type aliases for `TypedUuid<T>`. The macro provides an easy way to generate
 /// typed UUID kinds in bulk, and also to implement `JsonSchema` support with
-/// schemars 0.8.
+/// schemars 0.8, `ToSchema` support with utoipa 5, GraphQL scalar support
+/// with async-graphql 7, and TypeScript exports with ts-rs 11 or
+/// wasm-bindgen 0.2.
 ///
 /// # Basic usage
 ///
@@ -103,6 +108,13 @@ use quote::ToTokens;
 ///   *Optional, defaults to `{Name}Kind`*.
 /// - `alias`: The name of the type alias to use for the kind (a Rust
 ///   identifier). *Optional, defaults to `{Name}Uuid`*.
+/// - `constants`: A map of well-known UUIDs for the kind, such as
+///   `{ ROOT = "..." }`, where each value is a string literal. The UUIDs are
+///   validated at compile time. For each kind with constants, the macro
+///   generates a `{Alias}Constants` extension trait, implemented for the type
+///   alias, with an associated `const` per entry. If `schemars08` is enabled,
+///   the constants are also exported as `examples` in the JSON Schema.
+///   *Optional.*
 ///
 /// Per-kind customizations should generally be unnecessary; the conventionally
 /// generated type names should be sufficient for most use cases.
@@ -138,6 +150,42 @@ use quote::ToTokens;
 /// static_assertions::assert_impl_all!(MyUserKind: Ord);
 /// ```
 ///
+/// Sentinel IDs can be defined as constants:
+///
+/// ```
+/// use newtype_uuid_macros::impl_typed_uuid_kinds;
+///
+/// impl_typed_uuid_kinds! {
+///     kinds = {
+///         Organization = {
+///             constants = {
+///                 ROOT = "001de000-05e4-4000-8000-000000000000",
+///             },
+///         },
+///     },
+/// }
+///
+/// // The constants are available through the generated extension trait.
+/// assert_eq!(
+///     OrganizationUuid::ROOT.to_string(),
+///     "001de000-05e4-4000-8000-000000000000",
+/// );
+/// ```
+///
+/// Invalid UUIDs are rejected at compile time:
+///
+/// ```compile_fail
+/// use newtype_uuid_macros::impl_typed_uuid_kinds;
+///
+/// impl_typed_uuid_kinds! {
+///     kinds = {
+///         Organization = {
+///             constants = { ROOT = "not-a-uuid" },
+///         },
+///     },
+/// }
+/// ```
+///
 /// # Global settings
 ///
 /// This macro accepts global settings under a top-level `settings` map:
@@ -149,6 +197,16 @@ use quote::ToTokens;
 ///   identifier). *Optional, defaults to `newtype_uuid`.*
 /// - `schemars08`: If defined, generates JSON Schema support for the given
 ///   types using [`schemars` 0.8]. *Optional.*
+/// - `utoipa5`: If defined, generates OpenAPI schema support for the given
+///   types using [`utoipa` 5]. *Optional.*
+/// - `async_graphql7`: If defined, generates GraphQL scalar support for the
+///   given types using [`async-graphql` 7]. *Optional.*
+/// - `ts_rs11`: If defined, generates tests that export TypeScript declarations
+///   for the given types using [`ts-rs` 11]. *Optional.*
+/// - `defmt1`: If defined, implements `DefmtKind` for the given types, so that
+///   their typed UUIDs can be logged with [`defmt` 1]. *Optional.*
+/// - `wasm_bindgen02`: If defined, emits TypeScript declarations for the given
+///   types into [`wasm-bindgen` 0.2]'s generated bindings. *Optional.*
 ///
 /// ## JSON Schema support
 ///
@@ -183,10 +241,106 @@ use quote::ToTokens;
 ///   For more about `x-rust-type`, see the [`typify` documentation].
 ///
 /// [`schemars` 0.8]: https://docs.rs/schemars/0.8/schemars/
+/// [`utoipa` 5]: https://docs.rs/utoipa/5/utoipa/
+/// [`async-graphql` 7]: https://docs.rs/async-graphql/7/async_graphql/
+/// [`ts-rs` 11]: https://docs.rs/ts-rs/11/ts_rs/
+/// [`defmt` 1]: https://docs.rs/defmt/1/defmt/
+/// [`wasm-bindgen` 0.2]: https://docs.rs/wasm-bindgen/0.2/wasm_bindgen/
 /// [`typify`]: https://docs.rs/typify
 /// [`typify` documentation]:
 ///     https://github.com/oxidecomputer/typify#rust---schema---rust
 ///
+/// ## OpenAPI support
+///
+/// If the `utoipa5` global setting is defined, the macro generates
+/// `PartialSchema` and `ToSchema` implementations for the `Kind` instances
+/// using [utoipa 5]. With these, `TypedUuid<Kind>` also implements
+/// `ToSchema`: it is represented as a `string` with the `uuid` format, and
+/// named after the type alias (e.g. `UserUuid`).
+///
+/// **To enable OpenAPI support, you'll need to enable `newtype-uuid`'s
+/// `utoipa5` feature.**
+///
+/// Within `settings.utoipa5`, the options are the same as for `schemars08`:
+///
+/// - `attrs`: A list of attributes to apply to all generated `PartialSchema`
+///   and `ToSchema` implementations.
+/// - `rust_type`: Adds the `x-rust-type` extension to the schema, with the
+///   same `crate`, `version`, and `path` options as above.
+///
+/// [utoipa 5]: https://docs.rs/utoipa/5/utoipa/
+///
+/// ## GraphQL support
+///
+/// If the `async_graphql7` global setting is defined, the macro generates
+/// `Description` implementations for the `Kind` instances using
+/// [async-graphql 7]. With these, `TypedUuid<Kind>` is a GraphQL scalar: each
+/// kind is a distinct scalar named after the type alias (e.g. `UserUuid`), and
+/// represented as a string.
+///
+/// **To enable GraphQL support, you'll need to enable `newtype-uuid`'s
+/// `async-graphql7` feature.**
+///
+/// Within `settings.async_graphql7`, the options are:
+///
+/// - `attrs`: A list of attributes to apply to all generated `Description`
+///   implementations.
+///
+/// [async-graphql 7]: https://docs.rs/async-graphql/7/async_graphql/
+///
+/// ## TypeScript support
+///
+/// With `newtype-uuid`'s `ts-rs11` feature, `TypedUuid<Kind>` implements the
+/// `TS` trait from [ts-rs 11] as a branded string type named after the type
+/// alias: for example, `type UserUuid = string & { readonly __kind: "user" }`.
+/// Structs that derive `TS` and contain typed UUIDs import these declarations.
+///
+/// If the `ts_rs11` global setting is defined, the macro also generates a test
+/// for each kind that exports its declaration, the same as `#[ts(export)]`
+/// does for types deriving `TS`. Run `cargo test` to write the declarations.
+///
+/// Within `settings.ts_rs11`, the options are:
+///
+/// - `attrs`: A list of attributes to apply to all generated tests.
+///
+/// [ts-rs 11]: https://docs.rs/ts-rs/11/ts_rs/
+///
+/// ## defmt support
+///
+/// With `newtype-uuid`'s `defmt1` feature, `TypedUuid<Kind>` implements
+/// [defmt 1]'s `Format` trait if `Kind` implements `DefmtKind`, which provides
+/// the tag as an interned string. defmt can only intern string literals, so if
+/// the `defmt1` global setting is defined, the macro implements `DefmtKind` for
+/// each kind.
+///
+/// Within `settings.defmt1`, the options are:
+///
+/// - `attrs`: A list of attributes to apply to all generated `DefmtKind`
+///   implementations.
+///
+/// [defmt 1]: https://docs.rs/defmt/1/defmt/
+///
+/// ## WebAssembly support
+///
+/// With `newtype-uuid`'s `wasm-bindgen02` feature, `TypedUuid<Kind>` can be
+/// passed to and returned from JavaScript via [wasm-bindgen 0.2], as a string.
+/// Only values are supported, not `&TypedUuid<Kind>` or `Vec<TypedUuid<Kind>>`.
+/// If JavaScript passes a string that isn't a valid UUID, an exception is
+/// thrown; to handle invalid values, take a `JsValue` and convert it with
+/// `TypedUuid::try_from`.
+///
+/// If the `wasm_bindgen02` global setting is defined, the macro also adds a
+/// `typescript_custom_section` for each kind, so that the `.d.ts` file
+/// generated by wasm-bindgen declares the same branded type as ts-rs does: for
+/// example, `export type UserUuid = string & { readonly __kind: "user" };`.
+/// (Signatures of exported functions still refer to these types as `string`.)
+///
+/// Within `settings.wasm_bindgen02`, the options are:
+///
+/// - `attrs`: A list of attributes to apply to all generated custom sections.
+///
+/// [wasm-bindgen 0.2]: https://docs.rs/wasm-bindgen/0.2/wasm_bindgen/
+///
 /// ## Examples
 ///
 /// An example with all global settings defined:
@@ -207,6 +361,26 @@ use quote::ToTokens;
 ///                 path = "my_crate::types",
 ///             },
 ///         },
+///         utoipa5 = {
+///             attrs = [#[cfg(feature = "utoipa")]],
+///             rust_type = {
+///                 crate = "my-crate",
+///                 version = "0.1.0",
+///                 path = "my_crate::types",
+///             },
+///         },
+///         async_graphql7 = {
+///             attrs = [#[cfg(feature = "async-graphql")]],
+///         },
+///         ts_rs11 = {
+///             attrs = [#[cfg(feature = "ts-rs")]],
+///         },
+///         defmt1 = {
+///             attrs = [#[cfg(feature = "defmt")]],
+///         },
+///         wasm_bindgen02 = {
+///             attrs = [#[cfg(feature = "wasm-bindgen")]],
+///         },
 ///     },
 ///     kinds = {
 ///         User = {},
@@ -229,3 +403,84 @@ pub fn impl_typed_uuid_kinds(input: TokenStream) -> TokenStream {
         .into_token_stream()
         .into()
 }
+
+/// A function-like procedural macro for typed UUID literals.
+///
+/// `typed_uuid!(Kind, "...")` expands to a `TypedUuid<Kind>`, and can be used
+/// in `const` and `static` items. The string is parsed at compile time, and
+/// must be in one of the formats accepted by `Uuid::parse_str`. If it isn't,
+/// the compile error points at the string.
+///
+/// # Examples
+///
+/// ```
+/// use newtype_uuid_macros::{impl_typed_uuid_kinds, typed_uuid};
+///
+/// impl_typed_uuid_kinds! {
+///     kinds = {
+///         User = {},
+///     },
+/// }
+///
+/// const ADMIN: UserUuid = typed_uuid!(UserKind, "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0");
+/// assert_eq!(ADMIN.to_string(), "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0");
+/// ```
+///
+/// An invalid UUID fails to compile:
+///
+/// ```compile_fail
+/// # use newtype_uuid_macros::{impl_typed_uuid_kinds, typed_uuid};
+/// # impl_typed_uuid_kinds! {
+/// #     kinds = {
+/// #         User = {},
+/// #     },
+/// # }
+/// const ADMIN: UserUuid = typed_uuid!(UserKind, "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a");
+/// ```
+///
+/// # Options
+///
+/// Options can be passed in after the string, as `key = value` pairs:
+///
+/// * `version`: The expected version of the UUID, from 1 to 8. If the UUID
+///   has a different version, compilation fails.
+/// * `newtype_uuid_crate`: The name of the `newtype-uuid` crate, if it has
+///   been renamed. Defaults to `newtype_uuid`.
+///
+/// ```
+/// # use newtype_uuid_macros::{impl_typed_uuid_kinds, typed_uuid};
+/// # impl_typed_uuid_kinds! {
+/// #     kinds = {
+/// #         User = {},
+/// #     },
+/// # }
+/// const ADMIN: UserUuid = typed_uuid!(
+///     UserKind,
+///     "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0",
+///     version = 4,
+/// );
+/// ```
+///
+/// ```compile_fail
+/// # use newtype_uuid_macros::{impl_typed_uuid_kinds, typed_uuid};
+/// # impl_typed_uuid_kinds! {
+/// #     kinds = {
+/// #         User = {},
+/// #     },
+/// # }
+/// // This is a v4 UUID, not a v7 UUID.
+/// const ADMIN: UserUuid = typed_uuid!(
+///     UserKind,
+///     "6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0",
+///     version = 7,
+/// );
+/// ```
+///
+/// For UUIDs that aren't literals, such as those read from the environment
+/// with `env!`, use `TypedUuid::parse_str_const` instead.
+#[proc_macro]
+pub fn typed_uuid(input: TokenStream) -> TokenStream {
+    literal::typed_uuid(input.into())
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
@@ -0,0 +1,102 @@
+//! Implementation of the `typed_uuid!` macro.
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use syn::{
+    Token,
+    parse::{Parse, ParseStream},
+};
+use uuid::Uuid;
+
+pub(crate) fn typed_uuid(input: TokenStream) -> syn::Result<TokenStream> {
+    let input: TypedUuidInput = syn::parse2(input)?;
+
+    let value = input.literal.value();
+    let uuid = Uuid::parse_str(&value).map_err(|error| {
+        syn::Error::new(
+            input.literal.span(),
+            format!("invalid UUID `{value}`: {error}"),
+        )
+    })?;
+
+    if let Some(version) = &input.version {
+        let expected: usize = version.base10_parse()?;
+        if !(1..=8).contains(&expected) {
+            return Err(syn::Error::new(
+                version.span(),
+                format!("UUID version must be between 1 and 8, found {expected}"),
+            ));
+        }
+        let actual = uuid.get_version_num();
+        if actual != expected {
+            return Err(syn::Error::new(
+                input.literal.span(),
+                format!("expected a version {expected} UUID, but `{value}` is version {actual}"),
+            ));
+        }
+    }
+
+    let newtype_uuid_crate = input
+        .newtype_uuid_crate
+        .unwrap_or_else(|| syn::Ident::new("newtype_uuid", Span::call_site()));
+    let kind = &input.kind;
+    let value = Literal::u128_unsuffixed(uuid.as_u128());
+
+    Ok(quote! {
+        ::#newtype_uuid_crate::TypedUuid::<#kind>::from_u128(#value)
+    })
+}
+
+/// The input to `typed_uuid!`: a kind, a string literal, and options.
+struct TypedUuidInput {
+    kind: syn::Type,
+    literal: syn::LitStr,
+    version: Option<syn::LitInt>,
+    newtype_uuid_crate: Option<syn::Ident>,
+}
+
+impl Parse for TypedUuidInput {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let kind = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let literal = input.parse()?;
+
+        let mut version = None;
+        let mut newtype_uuid_crate = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                // Allow a trailing comma.
+                break;
+            }
+
+            let key: syn::Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let is_duplicate = match key.to_string().as_str() {
+                "version" => version.replace(input.parse()?).is_some(),
+                "newtype_uuid_crate" => newtype_uuid_crate.replace(input.parse()?).is_some(),
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "unknown option `{key}` (expected `version` or `newtype_uuid_crate`)"
+                        ),
+                    ));
+                }
+            };
+            if is_duplicate {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("option `{key}` specified more than once"),
+                ));
+            }
+        }
+
+        Ok(Self {
+            kind,
+            literal,
+            version,
+            newtype_uuid_crate,
+        })
+    }
+}
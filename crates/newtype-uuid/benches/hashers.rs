@@ -0,0 +1,160 @@
+//! Benchmarks for the hashers in `newtype_uuid::collections`, compared to the
+//! standard library's default hasher.
+//!
+//! Run with `cargo bench -p newtype-uuid --features collections`.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use newtype_uuid::{
+    GenericUuid, TypedUuid, TypedUuidKind, TypedUuidTag,
+    collections::{RandomUuidState, TimeOrderedUuidState, TypedUuidBuildHasher},
+};
+use std::{
+    collections::{HashMap, hash_map::RandomState},
+    hash::BuildHasher,
+    hint::black_box,
+};
+use uuid::Builder;
+
+enum BenchKind {}
+
+impl TypedUuidKind for BenchKind {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("bench");
+        TAG
+    }
+}
+
+type BenchUuid = TypedUuid<BenchKind>;
+
+const NUM_KEYS: usize = 10_000;
+
+/// A small, deterministic PRNG (SplitMix64), so that runs are comparable.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut bytes = [0; N];
+        for chunk in bytes.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next().to_le_bytes()[..chunk.len()]);
+        }
+        bytes
+    }
+}
+
+fn v4_keys() -> Vec<BenchUuid> {
+    let mut rng = SplitMix64(0);
+    (0..NUM_KEYS)
+        .map(|_| {
+            BenchUuid::from_untyped_uuid(Builder::from_random_bytes(rng.next_bytes()).into_uuid())
+        })
+        .collect()
+}
+
+/// v7 keys, a few per millisecond, as generated by a busy service.
+fn v7_keys() -> Vec<BenchUuid> {
+    let mut rng = SplitMix64(0);
+    let start = 1_700_000_000_000;
+    (0..NUM_KEYS)
+        .map(|i| {
+            let uuid =
+                Builder::from_unix_timestamp_millis(start + (i / 4) as u64, &rng.next_bytes())
+                    .into_uuid();
+            BenchUuid::from_untyped_uuid(uuid)
+        })
+        .collect()
+}
+
+fn bench_hashers(c: &mut Criterion) {
+    for (version, keys) in [("v4", v4_keys()), ("v7", v7_keys())] {
+        let mut group = c.benchmark_group(format!("hash_one/{version}"));
+        bench_hash_one(&mut group, "std RandomState", RandomState::new(), &keys);
+        bench_hash_one(&mut group, "RandomUuidState", RandomUuidState::new(), &keys);
+        bench_hash_one(
+            &mut group,
+            "TimeOrderedUuidState",
+            TimeOrderedUuidState::new(),
+            &keys,
+        );
+        bench_hash_one(
+            &mut group,
+            "TypedUuidBuildHasher",
+            TypedUuidBuildHasher::new(),
+            &keys,
+        );
+        bench_hash_one(
+            &mut group,
+            "TypedUuidBuildHasher::remixed",
+            TypedUuidBuildHasher::remixed(),
+            &keys,
+        );
+        group.finish();
+
+        let mut group = c.benchmark_group(format!("map_lookup/{version}"));
+        bench_lookup(&mut group, "std RandomState", RandomState::new(), &keys);
+        bench_lookup(&mut group, "RandomUuidState", RandomUuidState::new(), &keys);
+        bench_lookup(
+            &mut group,
+            "TimeOrderedUuidState",
+            TimeOrderedUuidState::new(),
+            &keys,
+        );
+        bench_lookup(
+            &mut group,
+            "TypedUuidBuildHasher",
+            TypedUuidBuildHasher::new(),
+            &keys,
+        );
+        bench_lookup(
+            &mut group,
+            "TypedUuidBuildHasher::remixed",
+            TypedUuidBuildHasher::remixed(),
+            &keys,
+        );
+        group.finish();
+    }
+}
+
+fn bench_hash_one<S: BuildHasher>(
+    group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    name: &str,
+    state: S,
+    keys: &[BenchUuid],
+) {
+    group.bench_function(BenchmarkId::from_parameter(name), |b| {
+        b.iter(|| {
+            keys.iter()
+                .fold(0_u64, |acc, key| acc ^ state.hash_one(black_box(key)))
+        })
+    });
+}
+
+fn bench_lookup<S: BuildHasher>(
+    group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    name: &str,
+    state: S,
+    keys: &[BenchUuid],
+) {
+    let mut map = HashMap::with_capacity_and_hasher(keys.len(), state);
+    for (i, key) in keys.iter().enumerate() {
+        map.insert(*key, i);
+    }
+
+    group.bench_function(BenchmarkId::from_parameter(name), |b| {
+        b.iter(|| {
+            keys.iter()
+                .map(|key| map[black_box(key)])
+                .fold(0, usize::wrapping_add)
+        })
+    });
+}
+
+criterion_group!(benches, bench_hashers);
+criterion_main!(benches);
@@ -0,0 +1,869 @@
+//! Hash maps and sets keyed by [`TypedUuid`], with hashers tailored to UUIDs.
+//!
+//! [`TypedUuid`] implements [`Hash`](core::hash::Hash) by writing the UUID's 16
+//! bytes, so a `HashMap<TypedUuid<T>, V>` spends most of its hashing time
+//! running SipHash over bits that are, for most UUIDs, already random. The
+//! hashers in this module instead fold the UUID into a 64-bit hash with one or
+//! two 128-bit multiplications.
+//!
+//! The hashers are seeded randomly, per map, in the same way as
+//! [`std::collections::hash_map::RandomState`]. This makes it hard for an
+//! attacker to choose keys that collide, though, like other fast hashers, they
+//! aren't as strong as SipHash in that regard.
+//!
+//! Two hasher builders are provided:
+//!
+//! - [`RandomUuidState`], the default, for UUIDs whose bits are mostly random,
+//!   such as v4 UUIDs.
+//! - [`TimeOrderedUuidState`], for time-ordered UUIDs such as v7 (and v1 or
+//!   v6). Keys generated close together in time share most of their leading
+//!   bits, so this performs an extra round of mixing.
+//!
+//! If keys can't be chosen by an attacker, [`TypedUuidBuildHasher`] is faster
+//! still: it isn't seeded, and by default uses the UUID's own bits as the hash.
+//!
+//! To compare these hashers against the standard library's default, run `cargo
+//! bench -p newtype-uuid --features collections`.
+//!
+//! For caches and indexes that hold values of several kinds, [`KindMap`] stores
+//! one [`TypedUuidMap`] per kind, with a value type chosen per kind via
+//! [`KindMapValue`].
+//!
+//! [`SortedTypedUuids`] is a sorted, deduplicated list of UUIDs, with set
+//! operations and queries by v7 timestamp.
+//!
+//! [`TypedUuidMap`] and [`TypedUuidSet`] are aliases for [`HashMap`] and
+//! [`HashSet`] with these hashers, so they serialize the same way: with the
+//! `serde` feature, a map is serialized as an object keyed by UUID strings in
+//! human-readable formats such as JSON. With `schemars08`, their JSON schemas
+//! are also the same as those for [`HashMap`] and [`HashSet`].
+//!
+//! # Examples
+//!
+//! ```
+//! use newtype_uuid::{
+//!     TypedUuid, TypedUuidKind, TypedUuidTag,
+//!     collections::{TimeOrderedUuidState, TypedUuidMap},
+//! };
+//!
+//! enum UserKind {}
+//! impl TypedUuidKind for UserKind {
+//!     fn tag() -> TypedUuidTag {
+//!         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+//!         TAG
+//!     }
+//! }
+//!
+//! // Maps and sets are created with `default()` rather than `new()`.
+//! let mut names: TypedUuidMap<UserKind, &str> = TypedUuidMap::default();
+//! let id = TypedUuid::<UserKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+//! names.insert(id, "alice");
+//! assert_eq!(names.get(&id), Some(&"alice"));
+//!
+//! // For v7 UUIDs, use TimeOrderedUuidState instead.
+//! let mut v7_names: TypedUuidMap<UserKind, &str, TimeOrderedUuidState> = TypedUuidMap::default();
+//! v7_names.insert(id, "alice");
+//! ```
+
+use crate::{GenericUuid, TypedUuid, TypedUuidKind, TypedUuidTag};
+use core::{
+    any::{Any, TypeId},
+    fmt,
+    hash::{BuildHasher, Hasher},
+};
+use std::collections::{HashMap, HashSet, hash_map::RandomState};
+use uuid::Uuid;
+
+mod sorted;
+
+pub use sorted::SortedTypedUuids;
+
+/// A [`HashMap`] keyed by [`TypedUuid<T>`], using [`RandomUuidState`] by
+/// default.
+///
+/// Create one with [`TypedUuidMap::default`] or
+/// [`TypedUuidMap::with_capacity_and_hasher`].
+pub type TypedUuidMap<T, V, S = RandomUuidState> = HashMap<TypedUuid<T>, V, S>;
+
+/// A [`HashSet`] of [`TypedUuid<T>`], using [`RandomUuidState`] by default.
+///
+/// Create one with [`TypedUuidSet::default`] or
+/// [`TypedUuidSet::with_capacity_and_hasher`].
+pub type TypedUuidSet<T, S = RandomUuidState> = HashSet<TypedUuid<T>, S>;
+
+/// A [`BuildHasher`] for UUIDs whose bits are mostly random, such as v4 UUIDs.
+///
+/// Each UUID is hashed with a single seeded 128-bit multiplication.
+#[derive(Clone, Debug)]
+pub struct RandomUuidState {
+    seeds: Seeds,
+}
+
+impl RandomUuidState {
+    /// Creates a new `RandomUuidState` with random seeds.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            seeds: Seeds::random(),
+        }
+    }
+
+    /// Creates a new `RandomUuidState` with the given seeds.
+    ///
+    /// Hashes are deterministic for a given pair of seeds, so this should only
+    /// be used if keys can't be chosen by an attacker.
+    #[inline]
+    pub const fn with_seeds(k0: u64, k1: u64) -> Self {
+        Self {
+            seeds: Seeds::new(k0, k1),
+        }
+    }
+}
+
+impl Default for RandomUuidState {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for RandomUuidState {
+    type Hasher = UuidHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> UuidHasher {
+        UuidHasher::new(self.seeds, false)
+    }
+}
+
+/// A [`BuildHasher`] for time-ordered UUIDs, such as v7 UUIDs.
+///
+/// UUIDs generated close together in time share their leading timestamp bits,
+/// so after the seeded 128-bit multiplication that [`RandomUuidState`]
+/// performs, the result is mixed once more.
+#[derive(Clone, Debug)]
+pub struct TimeOrderedUuidState {
+    seeds: Seeds,
+}
+
+impl TimeOrderedUuidState {
+    /// Creates a new `TimeOrderedUuidState` with random seeds.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            seeds: Seeds::random(),
+        }
+    }
+
+    /// Creates a new `TimeOrderedUuidState` with the given seeds.
+    ///
+    /// Hashes are deterministic for a given pair of seeds, so this should only
+    /// be used if keys can't be chosen by an attacker.
+    #[inline]
+    pub const fn with_seeds(k0: u64, k1: u64) -> Self {
+        Self {
+            seeds: Seeds::new(k0, k1),
+        }
+    }
+}
+
+impl Default for TimeOrderedUuidState {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for TimeOrderedUuidState {
+    type Hasher = UuidHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> UuidHasher {
+        UuidHasher::new(self.seeds, true)
+    }
+}
+
+/// The [`Hasher`] built by [`RandomUuidState`] and [`TimeOrderedUuidState`].
+///
+/// This has a fast path for the 16-byte writes performed by [`TypedUuid`]'s
+/// [`Hash`](core::hash::Hash) implementation, but any type can be hashed with
+/// it.
+#[derive(Clone, Debug)]
+pub struct UuidHasher {
+    state: u64,
+    key: u64,
+    time_ordered: bool,
+}
+
+impl UuidHasher {
+    #[inline]
+    const fn new(seeds: Seeds, time_ordered: bool) -> Self {
+        Self {
+            state: seeds.k0,
+            key: seeds.k1,
+            time_ordered,
+        }
+    }
+}
+
+impl Hasher for UuidHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        if let Ok(bytes) = <[u8; 16]>::try_from(bytes) {
+            self.write_u128(u128::from_be_bytes(bytes));
+            return;
+        }
+
+        write_words(bytes, |word| self.write_u64(word));
+    }
+
+    #[inline]
+    fn write_u8(&mut self, n: u8) {
+        self.write_u64(n.into());
+    }
+
+    #[inline]
+    fn write_u16(&mut self, n: u16) {
+        self.write_u64(n.into());
+    }
+
+    #[inline]
+    fn write_u32(&mut self, n: u32) {
+        self.write_u64(n.into());
+    }
+
+    #[inline]
+    fn write_u64(&mut self, n: u64) {
+        self.state = folded_multiply(self.state ^ n, self.key ^ MULTIPLIER);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, n: u128) {
+        let (high, low) = ((n >> 64) as u64, n as u64);
+        self.state = folded_multiply(self.state ^ high, self.key ^ low);
+        if self.time_ordered {
+            self.state = folded_multiply(self.state, MULTIPLIER);
+        }
+    }
+
+    #[inline]
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+/// A [`BuildHasher`] that uses a UUID's own bits as its hash.
+///
+/// Most of a v4 UUID's bits are random, so there's no need to hash them
+/// again: [`TypedUuidHasher`] XORs the two 64-bit halves of the UUID together
+/// and uses the result directly. This is the fastest option for maps keyed by
+/// [`TypedUuid`], and can be used as `HashMap<TypedUuid<T>, V,
+/// TypedUuidBuildHasher>`.
+///
+/// This hasher is not seeded, so an attacker who can choose keys can also
+/// choose their hashes. Only use it with UUIDs that are generated by trusted
+/// code, and prefer [`RandomUuidState`] otherwise.
+///
+/// # Non-random UUIDs
+///
+/// Passing bits through is only appropriate if they're random. For other
+/// UUIDs, such as time-ordered v7 UUIDs (whose leading bits are shared by
+/// UUIDs generated around the same time) or name-based v3 and v5 UUIDs
+/// derived from predictable inputs, use [`Self::remixed`] to mix the bits with
+/// a 128-bit multiplication first.
+///
+/// Maps created with [`Default`], including by deserialization, don't remix.
+///
+/// # Examples
+///
+/// ```
+/// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag, collections::TypedUuidBuildHasher};
+/// use std::collections::HashMap;
+///
+/// enum UserKind {}
+/// impl TypedUuidKind for UserKind {
+///     fn tag() -> TypedUuidTag {
+///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+///         TAG
+///     }
+/// }
+///
+/// let id = TypedUuid::<UserKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+///
+/// let mut names: HashMap<TypedUuid<UserKind>, &str, TypedUuidBuildHasher> = HashMap::default();
+/// names.insert(id, "alice");
+///
+/// // For v7 UUIDs:
+/// let mut v7_names = HashMap::with_hasher(TypedUuidBuildHasher::remixed());
+/// v7_names.insert(id, "alice");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TypedUuidBuildHasher {
+    remix: bool,
+}
+
+impl TypedUuidBuildHasher {
+    /// Creates a new `TypedUuidBuildHasher` that passes UUID bits through.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { remix: false }
+    }
+
+    /// Creates a new `TypedUuidBuildHasher` that mixes UUID bits, for UUIDs
+    /// that aren't random.
+    #[inline]
+    pub const fn remixed() -> Self {
+        Self { remix: true }
+    }
+
+    /// Returns true if this hasher mixes UUID bits.
+    #[inline]
+    pub const fn is_remixed(&self) -> bool {
+        self.remix
+    }
+}
+
+impl BuildHasher for TypedUuidBuildHasher {
+    type Hasher = TypedUuidHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> TypedUuidHasher {
+        TypedUuidHasher {
+            hash: 0,
+            remix: self.remix,
+        }
+    }
+}
+
+/// The [`Hasher`] built by [`TypedUuidBuildHasher`].
+///
+/// Types other than [`TypedUuid`] can be hashed with this as well, with an
+/// FxHash-style multiply and rotate per word. This is fast but weak, so it is
+/// best suited to keys that contain a UUID.
+#[derive(Clone, Debug)]
+pub struct TypedUuidHasher {
+    hash: u64,
+    remix: bool,
+}
+
+impl Hasher for TypedUuidHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        if let Ok(bytes) = <[u8; 16]>::try_from(bytes) {
+            self.write_u128(u128::from_be_bytes(bytes));
+            return;
+        }
+
+        write_words(bytes, |word| self.write_u64(word));
+    }
+
+    #[inline]
+    fn write_u8(&mut self, n: u8) {
+        self.write_u64(n.into());
+    }
+
+    #[inline]
+    fn write_u16(&mut self, n: u16) {
+        self.write_u64(n.into());
+    }
+
+    #[inline]
+    fn write_u32(&mut self, n: u32) {
+        self.write_u64(n.into());
+    }
+
+    #[inline]
+    fn write_u64(&mut self, n: u64) {
+        // Only used for keys other than UUIDs, which are written as a single
+        // 16-byte slice.
+        self.hash = (self.hash.rotate_left(5) ^ n).wrapping_mul(MULTIPLIER);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, n: u128) {
+        // UUID bytes are read in big-endian order, as with `Uuid::as_u128`.
+        // The low bits of the hash, which hash tables use to pick a bucket,
+        // then come from the last byte of each half. These are random in both
+        // v4 and v7 UUIDs, while the version and variant bits end up in the
+        // middle.
+        let (high, low) = ((n >> 64) as u64, n as u64);
+        self.hash = if self.remix {
+            folded_multiply(self.hash ^ high, low ^ MULTIPLIER)
+        } else {
+            self.hash ^ high ^ low
+        };
+    }
+
+    #[inline]
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// A [`TypedUuidKind`] whose values can be stored in a [`KindMap`].
+///
+/// The associated [`Value`](Self::Value) type is the type of value stored for
+/// UUIDs of this kind, so that [`KindMap::get`] and friends are statically
+/// typed.
+///
+/// # Examples
+///
+/// ```
+/// use newtype_uuid::{TypedUuidKind, TypedUuidTag, collections::KindMapValue};
+///
+/// struct User {
+///     name: String,
+/// }
+///
+/// enum UserKind {}
+/// impl TypedUuidKind for UserKind {
+///     fn tag() -> TypedUuidTag {
+///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+///         TAG
+///     }
+/// }
+///
+/// impl KindMapValue for UserKind {
+///     type Value = User;
+/// }
+/// ```
+pub trait KindMapValue: TypedUuidKind {
+    /// The type of value stored for UUIDs of this kind.
+    type Value: Any + Send + Sync;
+}
+
+/// A map from [`TypedUuid`]s of any kind to values, where the type of each
+/// value is determined by its kind.
+///
+/// A `KindMap` holds one [`TypedUuidMap`] per kind, keyed by the kind's
+/// [`TypeId`]. Typed access, through methods like [`get`](Self::get) and
+/// [`iter`](Self::iter), is checked at compile time via [`KindMapValue`].
+/// [`iter_untyped`](Self::iter_untyped) visits entries of every kind, along
+/// with each kind's [`TypedUuidTag`].
+///
+/// # Examples
+///
+/// ```
+/// use newtype_uuid::{
+///     GenericUuid, TypedUuid, TypedUuidKind, TypedUuidTag,
+///     collections::{KindMap, KindMapValue},
+/// };
+///
+/// enum UserKind {}
+/// impl TypedUuidKind for UserKind {
+///     fn tag() -> TypedUuidTag {
+///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+///         TAG
+///     }
+/// }
+/// impl KindMapValue for UserKind {
+///     type Value = String;
+/// }
+///
+/// enum OrgKind {}
+/// impl TypedUuidKind for OrgKind {
+///     fn tag() -> TypedUuidTag {
+///         const TAG: TypedUuidTag = TypedUuidTag::new("org");
+///         TAG
+///     }
+/// }
+/// impl KindMapValue for OrgKind {
+///     type Value = u32;
+/// }
+///
+/// let user_id = TypedUuid::<UserKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+/// let org_id = TypedUuid::<OrgKind>::from_u128(0x6bb9fe4d_ae0c_4a1e_9c4e_56a4e1e0b9f4);
+///
+/// let mut cache = KindMap::new();
+/// cache.insert(user_id, "alice".to_owned());
+/// cache.insert(org_id, 42);
+///
+/// // Lookups are statically typed.
+/// let name: Option<&String> = cache.get(user_id);
+/// assert_eq!(name.map(String::as_str), Some("alice"));
+/// assert_eq!(cache.get(org_id), Some(&42));
+/// assert_eq!(cache.len(), 2);
+///
+/// // Entries of all kinds can be visited together.
+/// for (tag, uuid, value) in cache.iter_untyped() {
+///     if tag.as_str() == "org" {
+///         assert_eq!(uuid, org_id.into_untyped_uuid());
+///         assert_eq!(value.downcast_ref::<u32>(), Some(&42));
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct KindMap {
+    maps: HashMap<TypeId, Box<dyn ErasedKindMap>>,
+}
+
+impl KindMap {
+    /// Creates an empty `KindMap`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total number of entries, across all kinds.
+    pub fn len(&self) -> usize {
+        self.maps.values().map(|map| map.len()).sum()
+    }
+
+    /// Returns true if there are no entries of any kind.
+    pub fn is_empty(&self) -> bool {
+        self.maps.values().all(|map| map.len() == 0)
+    }
+
+    /// Removes all entries of every kind.
+    pub fn clear(&mut self) {
+        self.maps.clear();
+    }
+
+    /// Inserts a value for `id`, returning the previous value if there was
+    /// one.
+    pub fn insert<T: KindMapValue>(
+        &mut self,
+        id: TypedUuid<T>,
+        value: T::Value,
+    ) -> Option<T::Value> {
+        self.kind_mut::<T>().insert(id, value)
+    }
+
+    /// Returns a reference to the value for `id`.
+    pub fn get<T: KindMapValue>(&self, id: TypedUuid<T>) -> Option<&T::Value> {
+        self.kind::<T>()?.get(&id)
+    }
+
+    /// Returns a mutable reference to the value for `id`.
+    pub fn get_mut<T: KindMapValue>(&mut self, id: TypedUuid<T>) -> Option<&mut T::Value> {
+        self.downcast_mut::<T>()?.get_mut(&id)
+    }
+
+    /// Returns true if there's a value for `id`.
+    pub fn contains_key<T: KindMapValue>(&self, id: TypedUuid<T>) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Removes and returns the value for `id`.
+    pub fn remove<T: KindMapValue>(&mut self, id: TypedUuid<T>) -> Option<T::Value> {
+        self.downcast_mut::<T>()?.remove(&id)
+    }
+
+    /// Returns the map of entries for kind `T`, if any entries of that kind
+    /// have been inserted.
+    pub fn kind<T: KindMapValue>(&self) -> Option<&TypedUuidMap<T, T::Value>> {
+        let map = self.maps.get(&TypeId::of::<T>())?;
+        Some(
+            map.as_any()
+                .downcast_ref()
+                .expect("maps are keyed by the TypeId of their kind"),
+        )
+    }
+
+    /// Returns the map of entries for kind `T`, creating an empty one if
+    /// necessary.
+    ///
+    /// This can be used to access the full [`HashMap`] API, such as
+    /// [`HashMap::entry`], for a single kind.
+    pub fn kind_mut<T: KindMapValue>(&mut self) -> &mut TypedUuidMap<T, T::Value> {
+        self.maps
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(TypedUuidMap::<T, T::Value>::default()))
+            .as_any_mut()
+            .downcast_mut()
+            .expect("maps are keyed by the TypeId of their kind")
+    }
+
+    /// Iterates over the entries of kind `T`, in arbitrary order.
+    pub fn iter<T: KindMapValue>(&self) -> impl Iterator<Item = (TypedUuid<T>, &T::Value)> {
+        self.kind::<T>()
+            .into_iter()
+            .flat_map(|map| map.iter().map(|(id, value)| (*id, value)))
+    }
+
+    /// Iterates over the entries of every kind, in arbitrary order.
+    ///
+    /// Each entry is yielded along with the tag of its kind. Values can be
+    /// downcast to the kind's [`KindMapValue::Value`] type.
+    pub fn iter_untyped(&self) -> impl Iterator<Item = (TypedUuidTag, Uuid, &dyn Any)> {
+        self.maps.values().flat_map(|map| {
+            let tag = map.tag();
+            map.iter_untyped()
+                .map(move |(uuid, value)| (tag.clone(), uuid, value))
+        })
+    }
+
+    fn downcast_mut<T: KindMapValue>(&mut self) -> Option<&mut TypedUuidMap<T, T::Value>> {
+        let map = self.maps.get_mut(&TypeId::of::<T>())?;
+        Some(
+            map.as_any_mut()
+                .downcast_mut()
+                .expect("maps are keyed by the TypeId of their kind"),
+        )
+    }
+}
+
+impl fmt::Debug for KindMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Values aren't required to implement Debug, so only show the number
+        // of entries for each kind.
+        f.debug_map()
+            .entries(self.maps.values().map(|map| (map.tag(), map.len())))
+            .finish()
+    }
+}
+
+/// A [`TypedUuidMap`] with its kind and value types erased.
+trait ErasedKindMap: Send + Sync {
+    fn tag(&self) -> TypedUuidTag;
+    fn len(&self) -> usize;
+    fn iter_untyped(&self) -> Box<dyn Iterator<Item = (Uuid, &dyn Any)> + '_>;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: KindMapValue> ErasedKindMap for TypedUuidMap<T, T::Value> {
+    fn tag(&self) -> TypedUuidTag {
+        T::tag()
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn iter_untyped(&self) -> Box<dyn Iterator<Item = (Uuid, &dyn Any)> + '_> {
+        Box::new(
+            self.iter()
+                .map(|(id, value)| (id.into_untyped_uuid(), value as &dyn Any)),
+        )
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// An odd constant with well-distributed bits (the PCG multiplier).
+const MULTIPLIER: u64 = 0x5851_f42d_4c95_7f2d;
+
+/// Multiplies `x` and `y` as 128-bit integers, then folds the result into 64
+/// bits. This is the mixing step used by hashers like foldhash.
+#[inline]
+const fn folded_multiply(x: u64, y: u64) -> u64 {
+    let full = (x as u128).wrapping_mul(y as u128);
+    (full as u64) ^ ((full >> 64) as u64)
+}
+
+/// Splits `bytes` into little-endian words and passes each one to
+/// `write_u64`. This is the fallback for inputs other than UUIDs.
+#[inline]
+fn write_words(bytes: &[u8], mut write_u64: impl FnMut(u64)) {
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let mut word = [0; 8];
+        word.copy_from_slice(chunk);
+        write_u64(u64::from_le_bytes(word));
+    }
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        let mut word = [0; 8];
+        word[..rest.len()].copy_from_slice(rest);
+        // Include the length so that trailing zeroes aren't ignored.
+        write_u64(u64::from_le_bytes(word) ^ ((rest.len() as u64) << 56));
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Seeds {
+    k0: u64,
+    k1: u64,
+}
+
+impl Seeds {
+    #[inline]
+    const fn new(k0: u64, k1: u64) -> Self {
+        Self { k0, k1 }
+    }
+
+    /// Derives seeds from the standard library's `RandomState`, which is
+    /// seeded randomly per thread and changes with each instance.
+    #[inline]
+    fn random() -> Self {
+        let state = RandomState::new();
+        Self::new(state.hash_one(0_u64), state.hash_one(1_u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum TestKind {}
+
+    impl TypedUuidKind for TestKind {
+        fn tag() -> TypedUuidTag {
+            const TAG: TypedUuidTag = TypedUuidTag::new("test");
+            TAG
+        }
+    }
+
+    impl KindMapValue for TestKind {
+        type Value = &'static str;
+    }
+
+    #[test]
+    fn test_hash_distribution() {
+        // Sequential, time-ordered keys: each one is a v7 UUID a millisecond
+        // after the last, with the same random bits.
+        let keys: Vec<TypedUuid<TestKind>> = (0..1024_u128)
+            .map(|i| TypedUuid::from_u128(0x01890a5d_ac96_774b_bcce_b302099a8057 + (i << 80)))
+            .collect();
+
+        fn distinct_buckets(state: &impl BuildHasher, keys: &[TypedUuid<TestKind>]) -> usize {
+            let mut buckets: Vec<u64> = keys.iter().map(|k| state.hash_one(k) & 1023).collect();
+            buckets.sort_unstable();
+            buckets.dedup();
+            buckets.len()
+        }
+
+        // A perfectly random hash would fill about 1 - 1/e of the buckets,
+        // i.e. ~647.
+        for seeds in [(0, 0), (1, 2), (u64::MAX, 0x1234_5678)] {
+            let random = RandomUuidState::with_seeds(seeds.0, seeds.1);
+            let time_ordered = TimeOrderedUuidState::with_seeds(seeds.0, seeds.1);
+            let random_buckets = distinct_buckets(&random, &keys);
+            let time_ordered_buckets = distinct_buckets(&time_ordered, &keys);
+            assert!(random_buckets > 550, "{seeds:?}: {random_buckets}");
+            assert!(
+                time_ordered_buckets > 550,
+                "{seeds:?}: {time_ordered_buckets}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_seeds() {
+        let uuid = TypedUuid::<TestKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+
+        // Hashes are deterministic for fixed seeds, and depend on them.
+        let a = RandomUuidState::with_seeds(1, 2);
+        assert_eq!(a.hash_one(uuid), a.clone().hash_one(uuid));
+        assert_ne!(
+            a.hash_one(uuid),
+            RandomUuidState::with_seeds(1, 3).hash_one(uuid)
+        );
+        assert_ne!(
+            RandomUuidState::new().hash_one(uuid),
+            RandomUuidState::new().hash_one(uuid),
+        );
+    }
+
+    #[test]
+    fn test_pass_through() {
+        let uuid = TypedUuid::<TestKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+        let state = TypedUuidBuildHasher::new();
+
+        // The hash is the XOR of the UUID's two halves.
+        assert_eq!(
+            state.hash_one(uuid),
+            0xdffc_3068_1cd6_47d5 ^ 0xb2f3_636b_41b0_7084,
+        );
+    }
+
+    #[test]
+    fn test_map_and_set() {
+        let a = TypedUuid::<TestKind>::from_u128(1);
+        let b = TypedUuid::<TestKind>::from_u128(2);
+
+        let mut map: TypedUuidMap<TestKind, &str> = TypedUuidMap::default();
+        map.insert(a, "a");
+        map.insert(b, "b");
+        assert_eq!(map.get(&a), Some(&"a"));
+        assert_eq!(map.get(&b), Some(&"b"));
+
+        let set: TypedUuidSet<TestKind, TimeOrderedUuidState> = [a, b, a].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&a));
+    }
+
+    #[test]
+    fn test_kind_map() {
+        enum OtherKind {}
+
+        impl TypedUuidKind for OtherKind {
+            fn tag() -> TypedUuidTag {
+                const TAG: TypedUuidTag = TypedUuidTag::new("other");
+                TAG
+            }
+        }
+
+        impl KindMapValue for OtherKind {
+            type Value = Vec<u32>;
+        }
+
+        let test_id = TypedUuid::<TestKind>::from_u128(1);
+        // The same UUID, but of a different kind.
+        let other_id = TypedUuid::<OtherKind>::from_u128(1);
+
+        let mut map = KindMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.get(test_id), None);
+        assert_eq!(map.remove(other_id), None);
+
+        assert_eq!(map.insert(test_id, "a"), None);
+        assert_eq!(map.insert(test_id, "b"), Some("a"));
+        assert_eq!(map.insert(other_id, vec![1]), None);
+        map.get_mut(other_id).unwrap().push(2);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(test_id), Some(&"b"));
+        assert_eq!(map.get(other_id), Some(&vec![1, 2]));
+        assert_eq!(
+            map.iter::<TestKind>().collect::<Vec<_>>(),
+            [(test_id, &"b")]
+        );
+
+        let mut untyped: Vec<_> = map
+            .iter_untyped()
+            .map(|(tag, uuid, value)| {
+                let value = match tag.as_str() {
+                    "test" => value.downcast_ref::<&str>().unwrap().to_string(),
+                    "other" => format!("{:?}", value.downcast_ref::<Vec<u32>>().unwrap()),
+                    other => panic!("unexpected tag {other}"),
+                };
+                (tag, uuid, value)
+            })
+            .collect();
+        untyped.sort();
+        assert_eq!(
+            untyped,
+            [
+                (OtherKind::tag(), Uuid::from_u128(1), "[1, 2]".to_owned()),
+                (TestKind::tag(), Uuid::from_u128(1), "b".to_owned()),
+            ]
+        );
+        let debug = format!("{map:?}");
+        assert!(debug.contains(r#"TypedUuidTag("test"): 1"#), "{debug}");
+        assert!(debug.contains(r#"TypedUuidTag("other"): 1"#), "{debug}");
+
+        assert_eq!(map.remove(test_id), Some("b"));
+        assert!(!map.contains_key(test_id));
+        assert_eq!(map.len(), 1);
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.iter::<OtherKind>().count(), 0);
+    }
+}
@@ -0,0 +1,658 @@
+use crate::{TypedUuid, TypedUuidKind};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Bound, Deref, RangeBounds},
+};
+use std::{slice, vec};
+
+/// A sorted, deduplicated list of [`TypedUuid`]s.
+///
+/// This is useful for large lists of IDs, such as those passed to batch
+/// queries, that would otherwise be repeatedly sorted and deduplicated as a
+/// `Vec<TypedUuid<T>>`. Compared to a [`TypedUuidSet`](super::TypedUuidSet), it
+/// uses less memory, and supports:
+///
+/// - [`union`](Self::union), [`intersection`](Self::intersection) and
+///   [`difference`](Self::difference), each in linear time.
+/// - Membership tests via binary search, with [`contains`](Self::contains).
+/// - Queries by timestamp for v7 UUIDs, with
+///   [`range_by_unix_millis`](Self::range_by_unix_millis).
+///
+/// `SortedTypedUuids<T>` dereferences to `[TypedUuid<T>]`, so slice methods
+/// such as `iter`, `first` and `last` are also available.
+///
+/// # Serialization
+///
+/// With the `serde` feature, `SortedTypedUuids<T>` is serialized as a sequence
+/// of UUIDs in human-readable formats such as JSON. In other formats, it is
+/// serialized as a compact byte string: each v7 UUID is encoded as the
+/// difference between its timestamp and that of the previous v7 UUID,
+/// followed by its 10 remaining bytes, so v7 UUIDs generated close together in
+/// time take up 11 bytes each rather than 16. Other UUIDs take up 17 bytes
+/// each.
+///
+/// In either case, deserialized UUIDs are sorted and deduplicated, so input
+/// doesn't have to be in order.
+///
+/// # Examples
+///
+/// ```
+/// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag, collections::SortedTypedUuids};
+///
+/// enum UserKind {}
+/// impl TypedUuidKind for UserKind {
+///     fn tag() -> TypedUuidTag {
+///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+///         TAG
+///     }
+/// }
+///
+/// let a = TypedUuid::<UserKind>::from_u128(1);
+/// let b = TypedUuid::<UserKind>::from_u128(2);
+/// let c = TypedUuid::<UserKind>::from_u128(3);
+///
+/// let left: SortedTypedUuids<UserKind> = [c, a, b, a].into_iter().collect();
+/// assert_eq!(left.as_slice(), [a, b, c]);
+/// assert!(left.contains(b));
+///
+/// let right: SortedTypedUuids<UserKind> = [b].into_iter().collect();
+/// assert_eq!(left.intersection(&right).as_slice(), [b]);
+/// assert_eq!(left.difference(&right).as_slice(), [a, c]);
+/// ```
+pub struct SortedTypedUuids<T: TypedUuidKind> {
+    uuids: Vec<TypedUuid<T>>,
+}
+
+impl<T: TypedUuidKind> SortedTypedUuids<T> {
+    /// Creates an empty `SortedTypedUuids`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { uuids: Vec::new() }
+    }
+
+    /// Returns the UUIDs as a sorted slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[TypedUuid<T>] {
+        &self.uuids
+    }
+
+    /// Returns the UUIDs as a sorted `Vec`.
+    #[inline]
+    pub fn into_vec(self) -> Vec<TypedUuid<T>> {
+        self.uuids
+    }
+
+    /// Returns true if `uuid` is in the list.
+    ///
+    /// This performs a binary search, so it takes logarithmic time.
+    #[inline]
+    pub fn contains(&self, uuid: TypedUuid<T>) -> bool {
+        self.uuids.binary_search(&uuid).is_ok()
+    }
+
+    /// Adds `uuid` to the list, returning true if it wasn't already present.
+    ///
+    /// This takes linear time, since later UUIDs are shifted to make room.
+    /// To add many UUIDs at once, use [`Extend::extend`] or
+    /// [`union`](Self::union) instead.
+    pub fn insert(&mut self, uuid: TypedUuid<T>) -> bool {
+        match self.uuids.binary_search(&uuid) {
+            Ok(_) => false,
+            Err(index) => {
+                self.uuids.insert(index, uuid);
+                true
+            }
+        }
+    }
+
+    /// Removes `uuid` from the list, returning true if it was present.
+    ///
+    /// This takes linear time, since later UUIDs are shifted to fill the gap.
+    pub fn remove(&mut self, uuid: TypedUuid<T>) -> bool {
+        match self.uuids.binary_search(&uuid) {
+            Ok(index) => {
+                self.uuids.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the UUIDs that are in `self`, `other`, or both.
+    pub fn union(&self, other: &Self) -> Self {
+        self.merge(
+            other,
+            Keep {
+                left: true,
+                both: true,
+                right: true,
+            },
+        )
+    }
+
+    /// Returns the UUIDs that are in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.merge(
+            other,
+            Keep {
+                left: false,
+                both: true,
+                right: false,
+            },
+        )
+    }
+
+    /// Returns the UUIDs that are in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.merge(
+            other,
+            Keep {
+                left: true,
+                both: false,
+                right: false,
+            },
+        )
+    }
+
+    /// Returns the UUIDs whose v7 timestamps, in milliseconds since the Unix
+    /// epoch, fall within `range`.
+    ///
+    /// A v7 UUID starts with its 48-bit timestamp, so sorting v7 UUIDs also
+    /// sorts them by time, and this takes logarithmic time. The list is
+    /// expected to only contain v7 UUIDs: any UUIDs of other versions whose
+    /// first 48 bits fall within `range` are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newtype_uuid::{
+    ///     GenericUuid, TypedUuid, TypedUuidKind, TypedUuidTag, collections::SortedTypedUuids,
+    /// };
+    ///
+    /// enum EventKind {}
+    /// impl TypedUuidKind for EventKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("event");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// // One event per second.
+    /// let events: SortedTypedUuids<EventKind> = (0..10)
+    ///     .map(|secs| {
+    ///         let millis = 1_700_000_000_000 + 1000 * secs;
+    ///         let uuid = uuid::Builder::from_unix_timestamp_millis(millis, &[0; 10]).into_uuid();
+    ///         TypedUuid::from_untyped_uuid(uuid)
+    ///     })
+    ///     .collect();
+    ///
+    /// // Events in the first 5 seconds.
+    /// let first = events.range_by_unix_millis(..1_700_000_005_000);
+    /// assert_eq!(first.len(), 5);
+    /// ```
+    pub fn range_by_unix_millis<R: RangeBounds<u64>>(&self, range: R) -> &[TypedUuid<T>] {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => self.partition_point_by_millis(|millis| millis < start),
+            Bound::Excluded(&start) => self.partition_point_by_millis(|millis| millis <= start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => self.partition_point_by_millis(|millis| millis <= end),
+            Bound::Excluded(&end) => self.partition_point_by_millis(|millis| millis < end),
+            Bound::Unbounded => self.uuids.len(),
+        };
+        &self.uuids[start..end.max(start)]
+    }
+
+    fn partition_point_by_millis(&self, pred: impl Fn(u64) -> bool) -> usize {
+        self.uuids
+            .partition_point(|uuid| pred(unix_millis(uuid.as_u128())))
+    }
+
+    fn merge(&self, other: &Self, keep: Keep) -> Self {
+        let (mut left, mut right) = (self.uuids.as_slice(), other.uuids.as_slice());
+        let mut uuids = Vec::with_capacity(match (keep.left, keep.right) {
+            (true, true) => left.len() + right.len(),
+            (true, false) => left.len(),
+            (false, true) => right.len(),
+            (false, false) => left.len().min(right.len()),
+        });
+
+        while let (Some(l), Some(r)) = (left.first(), right.first()) {
+            match l.cmp(r) {
+                Ordering::Less => {
+                    if keep.left {
+                        uuids.push(*l);
+                    }
+                    left = &left[1..];
+                }
+                Ordering::Greater => {
+                    if keep.right {
+                        uuids.push(*r);
+                    }
+                    right = &right[1..];
+                }
+                Ordering::Equal => {
+                    if keep.both {
+                        uuids.push(*l);
+                    }
+                    left = &left[1..];
+                    right = &right[1..];
+                }
+            }
+        }
+        if keep.left {
+            uuids.extend_from_slice(left);
+        }
+        if keep.right {
+            uuids.extend_from_slice(right);
+        }
+
+        Self { uuids }
+    }
+}
+
+/// Which UUIDs to keep when merging two lists.
+#[derive(Clone, Copy)]
+struct Keep {
+    left: bool,
+    both: bool,
+    right: bool,
+}
+
+/// Returns the first 48 bits of a UUID, which for v7 UUIDs are the timestamp
+/// in milliseconds since the Unix epoch.
+#[inline]
+const fn unix_millis(uuid: u128) -> u64 {
+    (uuid >> 80) as u64
+}
+
+impl<T: TypedUuidKind> Default for SortedTypedUuids<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: TypedUuidKind> Clone for SortedTypedUuids<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            uuids: self.uuids.clone(),
+        }
+    }
+}
+
+impl<T: TypedUuidKind> fmt::Debug for SortedTypedUuids<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.uuids).finish()
+    }
+}
+
+impl<T: TypedUuidKind> PartialEq for SortedTypedUuids<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.uuids == other.uuids
+    }
+}
+
+impl<T: TypedUuidKind> Eq for SortedTypedUuids<T> {}
+
+impl<T: TypedUuidKind> Hash for SortedTypedUuids<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uuids.hash(state);
+    }
+}
+
+impl<T: TypedUuidKind> Deref for SortedTypedUuids<T> {
+    type Target = [TypedUuid<T>];
+
+    #[inline]
+    fn deref(&self) -> &[TypedUuid<T>] {
+        &self.uuids
+    }
+}
+
+impl<T: TypedUuidKind> AsRef<[TypedUuid<T>]> for SortedTypedUuids<T> {
+    #[inline]
+    fn as_ref(&self) -> &[TypedUuid<T>] {
+        &self.uuids
+    }
+}
+
+impl<T: TypedUuidKind> From<Vec<TypedUuid<T>>> for SortedTypedUuids<T> {
+    /// Sorts and deduplicates `uuids`.
+    fn from(mut uuids: Vec<TypedUuid<T>>) -> Self {
+        uuids.sort_unstable();
+        uuids.dedup();
+        Self { uuids }
+    }
+}
+
+impl<T: TypedUuidKind> From<SortedTypedUuids<T>> for Vec<TypedUuid<T>> {
+    #[inline]
+    fn from(uuids: SortedTypedUuids<T>) -> Self {
+        uuids.uuids
+    }
+}
+
+impl<T: TypedUuidKind> FromIterator<TypedUuid<T>> for SortedTypedUuids<T> {
+    fn from_iter<I: IntoIterator<Item = TypedUuid<T>>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: TypedUuidKind> Extend<TypedUuid<T>> for SortedTypedUuids<T> {
+    fn extend<I: IntoIterator<Item = TypedUuid<T>>>(&mut self, iter: I) {
+        self.uuids.extend(iter);
+        self.uuids.sort_unstable();
+        self.uuids.dedup();
+    }
+}
+
+impl<T: TypedUuidKind> IntoIterator for SortedTypedUuids<T> {
+    type Item = TypedUuid<T>;
+    type IntoIter = vec::IntoIter<TypedUuid<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.uuids.into_iter()
+    }
+}
+
+impl<'a, T: TypedUuidKind> IntoIterator for &'a SortedTypedUuids<T> {
+    type Item = &'a TypedUuid<T>;
+    type IntoIter = slice::Iter<'a, TypedUuid<T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.uuids.iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_imp {
+    use super::*;
+    use crate::GenericUuid;
+    use core::marker::PhantomData;
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{self, SeqAccess, Visitor},
+    };
+    use uuid::Uuid;
+
+    impl<T: TypedUuidKind> Serialize for SortedTypedUuids<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                serializer.collect_seq(&self.uuids)
+            } else {
+                serializer.serialize_bytes(&encode_compact(&self.uuids))
+            }
+        }
+    }
+
+    impl<'de, T: TypedUuidKind> Deserialize<'de> for SortedTypedUuids<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                Vec::<TypedUuid<T>>::deserialize(deserializer).map(Self::from)
+            } else {
+                deserializer.deserialize_bytes(CompactVisitor(PhantomData))
+            }
+        }
+    }
+
+    struct CompactVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: TypedUuidKind> Visitor<'de> for CompactVisitor<T> {
+        type Value = SortedTypedUuids<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a compact list of {} UUIDs", T::tag())
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            let uuids = decode_compact(v).map_err(E::custom)?;
+            Ok(SortedTypedUuids::from(uuids))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            // Don't trust the size hint from the input for more than a small
+            // preallocation.
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+
+    /// The number of bytes following the timestamp in a v7 UUID.
+    const V7_REST_LEN: usize = 10;
+
+    /// Encodes sorted UUIDs.
+    ///
+    /// Each UUID starts with a varint header. For v7 UUIDs, this is the
+    /// difference between its timestamp and the previous v7 UUID's, shifted
+    /// left by one and with the low bit set, followed by the 10 bytes after
+    /// the timestamp. Other UUIDs have a header of 0, followed by all 16
+    /// bytes.
+    pub(super) fn encode_compact<T: TypedUuidKind>(uuids: &[TypedUuid<T>]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(uuids.len() * (1 + V7_REST_LEN));
+        let mut prev_millis = 0;
+        for uuid in uuids {
+            let bytes = uuid.as_bytes();
+            if uuid.get_version_num() == 7 {
+                // UUIDs are sorted, so timestamps never decrease.
+                let millis = unix_millis(uuid.as_u128());
+                write_varint(&mut out, ((millis - prev_millis) << 1) | 1);
+                out.extend_from_slice(&bytes[16 - V7_REST_LEN..]);
+                prev_millis = millis;
+            } else {
+                out.push(0);
+                out.extend_from_slice(bytes);
+            }
+        }
+        out
+    }
+
+    /// Decodes UUIDs written by [`encode_compact`].
+    pub(super) fn decode_compact<T: TypedUuidKind>(
+        mut input: &[u8],
+    ) -> Result<Vec<TypedUuid<T>>, &'static str> {
+        let mut uuids = Vec::with_capacity(input.len() / (1 + V7_REST_LEN));
+        let mut prev_millis: u64 = 0;
+        while !input.is_empty() {
+            let header = read_varint(&mut input)?;
+            let mut bytes = [0u8; 16];
+            if header & 1 == 1 {
+                let millis = prev_millis
+                    .checked_add(header >> 1)
+                    .filter(|millis| *millis >> 48 == 0)
+                    .ok_or("v7 UUID timestamp out of range")?;
+                let rest = take(&mut input, V7_REST_LEN)?;
+                if rest[0] >> 4 != 7 {
+                    return Err("UUID encoded as v7 has a different version");
+                }
+                bytes[..16 - V7_REST_LEN].copy_from_slice(&millis.to_be_bytes()[2..]);
+                bytes[16 - V7_REST_LEN..].copy_from_slice(rest);
+                prev_millis = millis;
+            } else if header == 0 {
+                bytes.copy_from_slice(take(&mut input, 16)?);
+            } else {
+                return Err("invalid UUID header");
+            }
+            uuids.push(TypedUuid::from_untyped_uuid(Uuid::from_bytes(bytes)));
+        }
+        Ok(uuids)
+    }
+
+    fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], &'static str> {
+        if input.len() < len {
+            return Err("unexpected end of input");
+        }
+        let (head, tail) = input.split_at(len);
+        *input = tail;
+        Ok(head)
+    }
+
+    /// Writes `n` as an unsigned LEB128 varint.
+    fn write_varint(out: &mut Vec<u8>, mut n: u64) {
+        while n >= 0x80 {
+            out.push((n as u8) | 0x80);
+            n >>= 7;
+        }
+        out.push(n as u8);
+    }
+
+    /// Reads an unsigned LEB128 varint.
+    fn read_varint(input: &mut &[u8]) -> Result<u64, &'static str> {
+        let mut n = 0u64;
+        for shift in (0..64).step_by(7) {
+            let (&byte, rest) = input.split_first().ok_or("unexpected end of input")?;
+            *input = rest;
+            let bits = u64::from(byte & 0x7f);
+            if (bits << shift) >> shift != bits {
+                return Err("varint overflows u64");
+            }
+            n |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(n);
+            }
+        }
+        Err("varint overflows u64")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GenericUuid, TypedUuidTag};
+
+    enum TestKind {}
+
+    impl TypedUuidKind for TestKind {
+        fn tag() -> TypedUuidTag {
+            const TAG: TypedUuidTag = TypedUuidTag::new("test");
+            TAG
+        }
+    }
+
+    type TestUuids = SortedTypedUuids<TestKind>;
+
+    fn uuids(values: &[u128]) -> TestUuids {
+        values.iter().copied().map(TypedUuid::from_u128).collect()
+    }
+
+    /// A v7 UUID with the given timestamp and random bits.
+    fn v7(millis: u64, random: u128) -> TypedUuid<TestKind> {
+        let random = random.to_be_bytes();
+        let mut bytes = [0; 10];
+        bytes.copy_from_slice(&random[6..]);
+        TypedUuid::from_untyped_uuid(
+            uuid::Builder::from_unix_timestamp_millis(millis, &bytes).into_uuid(),
+        )
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let left = uuids(&[5, 1, 3, 7, 3]);
+        let right = uuids(&[2, 3, 4, 7, 8]);
+        assert_eq!(left, uuids(&[1, 3, 5, 7]));
+
+        assert_eq!(left.union(&right), uuids(&[1, 2, 3, 4, 5, 7, 8]));
+        assert_eq!(left.intersection(&right), uuids(&[3, 7]));
+        assert_eq!(left.difference(&right), uuids(&[1, 5]));
+        assert_eq!(right.difference(&left), uuids(&[2, 4, 8]));
+        assert_eq!(left.union(&TestUuids::new()), left);
+        assert_eq!(left.intersection(&TestUuids::new()), TestUuids::new());
+
+        let mut set = left.clone();
+        assert!(set.contains(TypedUuid::from_u128(3)));
+        assert!(!set.contains(TypedUuid::from_u128(4)));
+        assert!(set.insert(TypedUuid::from_u128(4)));
+        assert!(!set.insert(TypedUuid::from_u128(4)));
+        assert!(set.remove(TypedUuid::from_u128(1)));
+        assert!(!set.remove(TypedUuid::from_u128(1)));
+        set.extend([TypedUuid::from_u128(9), TypedUuid::from_u128(3)]);
+        assert_eq!(set, uuids(&[3, 4, 5, 7, 9]));
+    }
+
+    #[test]
+    fn test_range_by_unix_millis() {
+        let set: TestUuids = (0..10).rev().map(|i| v7(1000 + i / 2, i.into())).collect();
+        assert_eq!(set.len(), 10);
+
+        assert_eq!(set.range_by_unix_millis(..).len(), 10);
+        assert_eq!(set.range_by_unix_millis(1001..1003), &set[2..6]);
+        assert_eq!(set.range_by_unix_millis(1001..=1003), &set[2..8]);
+        assert_eq!(
+            set.range_by_unix_millis((Bound::Excluded(1001), Bound::Unbounded)),
+            &set[4..],
+        );
+        assert_eq!(set.range_by_unix_millis(..1000).len(), 0);
+        assert_eq!(set.range_by_unix_millis(2000..).len(), 0);
+        assert_eq!(
+            set.range_by_unix_millis((Bound::Included(1003), Bound::Excluded(1001)))
+                .len(),
+            0,
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_compact_encoding() {
+        use serde_imp::{decode_compact, encode_compact};
+
+        // v7 UUIDs a few milliseconds apart take up 11 bytes each.
+        let set: TestUuids = (0..100)
+            .map(|i| {
+                v7(
+                    1_700_000_000_000 + 3 * i,
+                    u128::from(i) * 0x1234_5678_9abc_def1,
+                )
+            })
+            .collect();
+        let encoded = encode_compact(&set);
+        assert_eq!(encoded.len(), 100 * 11 + 5);
+        assert_eq!(
+            decode_compact::<TestKind>(&encoded).unwrap(),
+            set.as_slice()
+        );
+
+        // Other UUIDs take up 17 bytes each, and can be mixed with v7 UUIDs.
+        let mixed = set.union(&uuids(&[0, 1, u128::MAX]));
+        let encoded = encode_compact(&mixed);
+        assert_eq!(encoded.len(), 100 * 11 + 5 + 3 * 17);
+        assert_eq!(
+            decode_compact::<TestKind>(&encoded).unwrap(),
+            mixed.as_slice()
+        );
+
+        assert!(encode_compact::<TestKind>(&[]).is_empty());
+        assert!(decode_compact::<TestKind>(&[]).unwrap().is_empty());
+
+        // Invalid input.
+        for (input, error) in [
+            (&[0, 1, 2][..], "unexpected end of input"),
+            (&[2][..], "invalid UUID header"),
+            (
+                &[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0][..],
+                "UUID encoded as v7 has a different version",
+            ),
+            (&[0xff; 11][..], "varint overflows u64"),
+            (
+                &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f][..],
+                "v7 UUID timestamp out of range",
+            ),
+        ] {
+            assert_eq!(
+                decode_compact::<TestKind>(input),
+                Err(error),
+                "input: {input:?}"
+            );
+        }
+    }
+}
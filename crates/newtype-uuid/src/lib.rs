@@ -96,7 +96,8 @@
 //!
 //! - The `Display` and `FromStr` impls are forwarded to the underlying [`Uuid`].
 //! - If the `serde` feature is enabled, `TypedUuid` will serialize and deserialize using the same
-//!   format as [`Uuid`].
+//!   format as [`Uuid`]. To pick a specific format regardless of whether the serializer is
+//!   human-readable, use one of the helper modules in `newtype_uuid::serde`.
 //! - If the `schemars08` feature is enabled, [`TypedUuid`] will implement `JsonSchema` if the
 //!   corresponding [`TypedUuidKind`] implements `JsonSchema`.
 //!
@@ -115,10 +116,68 @@
 //! - `serde`: Enables serialization and deserialization support via Serde. *Not enabled by
 //!   default.*
 //! - `v4`: Enables the `new_v4` method for generating UUIDs. *Not enabled by default.*
+//! - `collections`: Adds the [`collections`] module, with [`TypedUuidMap`](collections::TypedUuidMap)
+//!   and [`TypedUuidSet`](collections::TypedUuidSet) types that use fast, randomly seeded hashers
+//!   tailored to UUIDs, along with the unseeded [`TypedUuidBuildHasher`](collections::TypedUuidBuildHasher)
+//!   and [`KindMap`](collections::KindMap), a map holding UUIDs of several kinds. Also adds
+//!   [`SortedTypedUuids`](collections::SortedTypedUuids), a sorted list of UUIDs with set
+//!   operations. Requires `std`. *Not enabled by default.*
 //! - `schemars08`: Enables support for generating JSON schemas via schemars 0.8. *Not enabled by
 //!   default.* Note that the format of the generated schema is **not currently part** of the stable
 //!   API, though we hope to stabilize it in the future.
+//! - `utoipa5`: Enables support for generating OpenAPI schemas via utoipa 5. *Not enabled by
+//!   default.* As with `schemars08`, the format of the generated schema is **not currently part**
+//!   of the stable API.
+//! - `async-graphql7`: Implements async-graphql 7's `ScalarType`, `InputType` and `OutputType`
+//!   for [`TypedUuid`], if the corresponding [`TypedUuidKind`] implements `Description`. Each kind
+//!   is a distinct scalar, named after the alias (e.g. `UserUuid`) or the tag. *Not enabled by
+//!   default.*
+//! - `ts-rs11`: Implements ts-rs 11's `TS` for [`TypedUuid`], as a branded string type such as
+//!   `type UserUuid = string & { readonly __kind: "user" }`. The type is named after the alias or
+//!   the tag. *Not enabled by default.*
 //! - `proptest1`: Enables support for generating `proptest::Arbitrary` instances of UUIDs. *Not enabled by default.*
+//!   The generated UUIDs can be configured via `TypedUuidParams`.
+//! - `arbitrary1`: Implements `arbitrary::Arbitrary` for [`TypedUuid`], for use with fuzzers like
+//!   `cargo fuzz`. Generated values are biased towards edge cases such as the nil and max UUIDs,
+//!   each UUID version, and non-RFC variants. *Not enabled by default.*
+//! - `quickcheck1`: Implements `quickcheck::Arbitrary` for [`TypedUuid`]. As with `arbitrary1`,
+//!   generated values cover edge cases such as the nil and max UUIDs and each UUID version, and
+//!   shrink towards the nil UUID. *Not enabled by default.*
+//! - `clap4`: Implements clap 4's `ValueParserFactory` for [`TypedUuid`], so typed UUIDs can be
+//!   used as command-line arguments. Both plain and tag-prefixed (`user:<uuid>`) values are
+//!   accepted, and error messages mention the expected kind. [`typed_uuid_arg`] creates an argument
+//!   that shows the kind in help text (e.g. `<USER_UUID>`). *Not enabled by default.*
+//! - `valuable01`: Implements `valuable::Valuable` for [`TypedUuid`], exposing it as a struct with
+//!   separate `kind` and `uuid` fields. *Not enabled by default.*
+//! - `tracing01`: Adds [`TypedUuid::as_tracing_value`], which records a UUID along with its kind in
+//!   `tracing` spans and events. With `valuable01` and `--cfg tracing_unstable`, the kind and UUID
+//!   are recorded as separate fields. *Not enabled by default.*
+//! - `defmt1`: Implements `defmt::Format` for [`TypedUuid<T>`] where `T` implements
+//!   [`DefmtKind`], formatting UUIDs as `kind:uuid`. The tag is interned, so only its index and
+//!   the 16 bytes of the UUID are sent over the wire. Works without `alloc`. *Not enabled by
+//!   default.*
+//! - `pyo3028`: Implements pyo3 0.28's `FromPyObject` and `IntoPyObject` for [`TypedUuid`],
+//!   converting to and from Python's `uuid.UUID`. Strings containing UUIDs can also be extracted,
+//!   and extraction errors mention the expected kind. *Not enabled by default.*
+//! - `wasm-bindgen02`: Implements wasm-bindgen 0.2's ABI traits for [`TypedUuid`], so typed UUIDs
+//!   can be passed to and returned from JavaScript by value, as strings. Strings that aren't valid
+//!   UUIDs throw an error mentioning the expected kind; to handle them instead, take a `JsValue`
+//!   and convert it with `TryFrom`. References and vectors of typed UUIDs aren't supported. With
+//!   the macro, TypeScript declarations for each kind can also be generated. *Not enabled by
+//!   default.*
+//! - `borsh1`: Implements `BorshSerialize` and `BorshDeserialize` from borsh 1.x, encoding UUIDs as
+//!   16 bytes. *Not enabled by default.*
+//! - `bincode2`: Implements `Encode`, `Decode` and `BorrowDecode` from bincode 2.x, encoding UUIDs
+//!   as 16 bytes. *Not enabled by default.*
+//! - `rkyv08`: Implements rkyv 0.8's `Archive`, `Serialize` and `Deserialize` for [`TypedUuid`],
+//!   archiving to `ArchivedTypedUuid<T>` (with `bytecheck` validation). *Not enabled by default.*
+//! - `bytemuck1`: Implements bytemuck's `Pod`, `Zeroable` and `TransparentWrapper<Uuid>` for
+//!   [`TypedUuid`], so byte buffers such as memory-mapped index files can be cast to slices of
+//!   typed UUIDs with `bytemuck::cast_slice`. *Not enabled by default.*
+//! - `miette7`: Implements miette 7's `Diagnostic` for [`ParseError`] and [`CheckedParseError`],
+//!   and adds [`TypedUuid::parse_spanned`] to record where the input came from. Rendered reports
+//!   point at the offending input (or the exact invalid character) and name the expected kind.
+//!   *Not enabled by default.*
 //!
 //! # Minimum supported Rust version (MSRV)
 //!
@@ -139,7 +198,9 @@
 //! - [`typed-uuid`](https://crates.io/crates/typed-uuid): generally similar, but with a few design
 //!   decisions that are different.
 
-#![forbid(unsafe_code)]
+// Unsafe code is only allowed in the modules implementing unsafe traits from
+// other crates, and each of those modules opts in explicitly.
+#![deny(unsafe_code)]
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
@@ -147,6 +208,14 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "collections")]
+pub mod collections;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod str_buf;
+
+pub use str_buf::{TypedUuidStr, TypedUuidStrError};
+
 /// Macro support for [`newtype-uuid-macros`].
 ///
 /// This module re-exports types needed for [`newtype-uuid-macros`] to work.
@@ -154,10 +223,20 @@ extern crate alloc;
 /// [`newtype-uuid-macros`]: https://docs.rs/newtype-uuid-macros
 #[doc(hidden)]
 pub mod macro_support {
+    #[cfg(feature = "async-graphql7")]
+    pub use async_graphql as async_graphql7;
+    #[cfg(feature = "defmt1")]
+    pub use defmt as defmt1;
     #[cfg(feature = "schemars08")]
     pub use schemars as schemars08;
-    #[cfg(feature = "schemars08")]
+    #[cfg(any(feature = "schemars08", feature = "utoipa5"))]
     pub use serde_json;
+    #[cfg(feature = "ts-rs11")]
+    pub use ts_rs as ts_rs11;
+    #[cfg(feature = "utoipa5")]
+    pub use utoipa as utoipa5;
+    #[cfg(feature = "wasm-bindgen02")]
+    pub use wasm_bindgen as wasm_bindgen02;
 }
 
 use core::{
@@ -175,8 +254,22 @@ use uuid::{Uuid, Version};
 ///
 /// For more, see [the library documentation](crate).
 #[repr(transparent)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent, bound = ""))]
+#[cfg_attr(
+    feature = "bytemuck1",
+    derive(bytemuck::Zeroable, bytemuck::TransparentWrapper),
+    zeroable(bound = ""),
+    transparent(Uuid)
+)]
+#[cfg_attr(
+    feature = "rkyv08",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(
+        archived = ArchivedTypedUuid,
+        attr(doc = "An archived [`TypedUuid`], with the same layout as an archived [`Uuid`].")
+    )
+)]
 pub struct TypedUuid<T: TypedUuidKind> {
     uuid: Uuid,
     _phantom: PhantomData<T>,
@@ -297,6 +390,187 @@ impl<T: TypedUuidKind> TypedUuid<T> {
         }
     }
 
+    /// Parses a UUID from a string, in a `const` context.
+    ///
+    /// This is meant for hard-coding well-known UUIDs: it accepts the same
+    /// formats as [`FromStr`], and when used to initialize a `const` or
+    /// `static`, invalid input is reported at compile time. For a compile
+    /// error that points at the invalid string, and to check the UUID's
+    /// version, use the `typed_uuid!` macro in
+    /// [`newtype-uuid-macros`](https://docs.rs/newtype-uuid-macros).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` isn't a valid UUID. Use [`FromStr`] to handle errors
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// const ADMIN: TypedUuid<UserKind> =
+    ///     TypedUuid::parse_str_const("6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a0");
+    /// assert_eq!(ADMIN.as_u128(), 0x6f2b94fc_31c4_4a0e_9b1e_2c4bd2e1f7a0);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+    /// # enum UserKind {}
+    /// # impl TypedUuidKind for UserKind {
+    /// #     fn tag() -> TypedUuidTag {
+    /// #         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    /// #         TAG
+    /// #     }
+    /// # }
+    /// // This fails to compile, since the UUID is one character short.
+    /// const ADMIN: TypedUuid<UserKind> =
+    ///     TypedUuid::parse_str_const("6f2b94fc-31c4-4a0e-9b1e-2c4bd2e1f7a");
+    /// ```
+    #[must_use]
+    pub const fn parse_str_const(input: &'static str) -> Self {
+        match Uuid::try_parse(input) {
+            Ok(uuid) => Self {
+                uuid,
+                _phantom: PhantomData,
+            },
+            Err(_) => panic!("invalid UUID string"),
+        }
+    }
+
+    /// Parses a UUID of this kind prefixed by its tag and a colon, e.g.
+    /// `user:dffc3068-1cd6-47d5-b2f3-636b41b07084`.
+    ///
+    /// This is the inverse of formatting with
+    /// [`to_prefixed_str_buf`](Self::to_prefixed_str_buf). If the prefix is
+    /// missing or names another kind, the error is
+    /// [`CheckedParseError::TagMismatch`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newtype_uuid::{CheckedParseError, TypedUuid, TypedUuidKind, TypedUuidTag};
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// let uuid =
+    ///     TypedUuid::<UserKind>::parse_prefixed("user:dffc3068-1cd6-47d5-b2f3-636b41b07084").unwrap();
+    /// assert_eq!(uuid.as_u128(), 0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+    ///
+    /// let error = TypedUuid::<UserKind>::parse_prefixed("org:dffc3068-1cd6-47d5-b2f3-636b41b07084")
+    ///     .unwrap_err();
+    /// assert!(matches!(error, CheckedParseError::TagMismatch { .. }));
+    /// ```
+    pub fn parse_prefixed(input: &str) -> Result<Self, CheckedParseError> {
+        let uuid = input
+            .strip_prefix(T::tag().as_str())
+            .and_then(|rest| rest.strip_prefix(':'))
+            .ok_or(CheckedParseError::TagMismatch { tag: T::tag() })?;
+        uuid.parse().map_err(|error: ParseError| {
+            // Report errors relative to the whole input, prefix included.
+            let kind = match error.kind {
+                ParseErrorKind::InvalidChar { character, index } => ParseErrorKind::InvalidChar {
+                    character,
+                    index: index + (input.len() - uuid.len()),
+                },
+                kind => kind,
+            };
+            ParseError::new::<T>(kind, input, error.error).into()
+        })
+    }
+
+    /// Parses a UUID of this kind, requiring it to have the given version.
+    ///
+    /// If the UUID is valid but has another version, the error is
+    /// [`CheckedParseError::WrongVersion`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newtype_uuid::{CheckedParseError, TypedUuid, TypedUuidKind, TypedUuidTag};
+    /// use uuid::Version;
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// let input = "dffc3068-1cd6-47d5-b2f3-636b41b07084";
+    /// assert!(TypedUuid::<UserKind>::parse_version(input, Version::Random).is_ok());
+    ///
+    /// let error = TypedUuid::<UserKind>::parse_version(input, Version::SortRand).unwrap_err();
+    /// assert!(matches!(
+    ///     error,
+    ///     CheckedParseError::WrongVersion {
+    ///         expected: 7,
+    ///         found: 4,
+    ///         ..
+    ///     },
+    /// ));
+    /// ```
+    pub fn parse_version(input: &str, version: Version) -> Result<Self, CheckedParseError> {
+        let uuid: Self = input.parse()?;
+        let expected = version as u8;
+        // The version is a 4-bit field, so this can't truncate.
+        let found = uuid.get_version_num() as u8;
+        if found != expected {
+            return Err(CheckedParseError::WrongVersion {
+                tag: T::tag(),
+                expected,
+                found,
+            });
+        }
+        Ok(uuid)
+    }
+
+    /// Parses a UUID of this kind, rejecting the nil UUID.
+    ///
+    /// This is useful for inputs where the nil UUID is a common placeholder
+    /// for a missing value. If the UUID is nil, the error is
+    /// [`CheckedParseError::NilDisallowed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newtype_uuid::{CheckedParseError, TypedUuid, TypedUuidKind, TypedUuidTag};
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// let error =
+    ///     TypedUuid::<UserKind>::parse_non_nil("00000000-0000-0000-0000-000000000000").unwrap_err();
+    /// assert!(matches!(error, CheckedParseError::NilDisallowed { .. }));
+    /// ```
+    pub fn parse_non_nil(input: &str) -> Result<Self, CheckedParseError> {
+        let uuid: Self = input.parse()?;
+        if uuid.is_nil() {
+            return Err(CheckedParseError::NilDisallowed { tag: T::tag() });
+        }
+        Ok(uuid)
+    }
+
     /// Creates a new, random UUID v4 of this type.
     #[inline]
     #[cfg(feature = "v4")]
@@ -690,6 +964,165 @@ impl<T: TypedUuidKind> TypedUuid<T> {
             _phantom: PhantomData,
         }
     }
+
+    /// Parses many UUIDs of this kind, such as a column of IDs read from a
+    /// file.
+    ///
+    /// Each input is parsed as with [`FromStr`]. If any of them fail to parse,
+    /// the returned error contains the index of the first failure.
+    ///
+    /// To append to an existing `Vec`, for example to reuse its allocation
+    /// across batches, use [`parse_many_into`](Self::parse_many_into).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// let uuids = TypedUuid::<UserKind>::parse_many([
+    ///     "dffc3068-1cd6-47d5-b2f3-636b41b07084",
+    ///     "01890a5d-ac96-774b-bcce-b302099a8057",
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(uuids.len(), 2);
+    ///
+    /// let error =
+    ///     TypedUuid::<UserKind>::parse_many(["dffc3068-1cd6-47d5-b2f3-636b41b07084", "bogus"])
+    ///         .unwrap_err();
+    /// assert_eq!(error.index, 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_many<I>(inputs: I) -> Result<alloc::vec::Vec<Self>, ParseManyError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut uuids = alloc::vec::Vec::new();
+        Self::parse_many_into(inputs, &mut uuids)?;
+        Ok(uuids)
+    }
+
+    /// Parses many UUIDs of this kind, appending them to `out`.
+    ///
+    /// This is like [`parse_many`](Self::parse_many), but reuses `out`'s
+    /// allocation. Indexes in errors are relative to the start of `inputs`,
+    /// and if an error occurs, `out` is truncated back to its original length.
+    #[cfg(feature = "alloc")]
+    pub fn parse_many_into<I>(
+        inputs: I,
+        out: &mut alloc::vec::Vec<Self>,
+    ) -> Result<(), ParseManyError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let original_len = out.len();
+        let inputs = inputs.into_iter();
+        out.reserve(inputs.size_hint().0);
+        for (index, input) in inputs.enumerate() {
+            match input.as_ref().parse() {
+                Ok(uuid) => out.push(uuid),
+                Err(error) => {
+                    out.truncate(original_len);
+                    return Err(ParseManyError { index, error });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes many UUIDs of this kind to `out`, in lowercase hyphenated form
+    /// and separated by `separator`.
+    ///
+    /// Unlike calling `to_string()` on each UUID, this doesn't allocate: each UUID is formatted into a buffer
+    /// on the stack, which is reused for the next one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// let uuids = [
+    ///     TypedUuid::<UserKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084),
+    ///     TypedUuid::<UserKind>::from_u128(0x01890a5d_ac96_774b_bcce_b302099a8057),
+    /// ];
+    /// let mut out = String::new();
+    /// TypedUuid::format_many_into(&uuids, "\n", &mut out).unwrap();
+    /// assert_eq!(
+    ///     out,
+    ///     "dffc3068-1cd6-47d5-b2f3-636b41b07084\n01890a5d-ac96-774b-bcce-b302099a8057",
+    /// );
+    /// ```
+    pub fn format_many_into<W: fmt::Write>(
+        uuids: &[Self],
+        separator: &str,
+        out: &mut W,
+    ) -> fmt::Result {
+        let mut buf = Uuid::encode_buffer();
+        for (index, uuid) in uuids.iter().enumerate() {
+            if index > 0 {
+                out.write_str(separator)?;
+            }
+            out.write_str(uuid.uuid.hyphenated().encode_lower(&mut buf))?;
+        }
+        Ok(())
+    }
+
+    /// Creates UUIDs of this kind from a slice of concatenated 16-byte UUIDs,
+    /// such as a column read from a binary file.
+    ///
+    /// Returns an error if the length of `bytes` isn't a multiple of 16.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// let mut bytes = [0u8; 32];
+    /// bytes[16..].fill(0xff);
+    /// let uuids = TypedUuid::<UserKind>::from_bytes_slice(&bytes).unwrap();
+    /// assert_eq!(uuids, [TypedUuid::nil(), TypedUuid::max()]);
+    ///
+    /// let error = TypedUuid::<UserKind>::from_bytes_slice(&bytes[..20]).unwrap_err();
+    /// assert_eq!(error.len, 20);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_bytes_slice(bytes: &[u8]) -> Result<alloc::vec::Vec<Self>, ByteLengthError> {
+        let chunks = bytes.chunks_exact(16);
+        if !chunks.remainder().is_empty() {
+            return Err(ByteLengthError {
+                len: bytes.len(),
+                tag: T::tag(),
+            });
+        }
+        Ok(chunks
+            .map(|chunk| Self::from_bytes(chunk.try_into().expect("chunk is 16 bytes")))
+            .collect())
+    }
 }
 
 // ---
@@ -752,10 +1185,8 @@ impl<T: TypedUuidKind> FromStr for TypedUuid<T> {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let uuid = Uuid::from_str(s).map_err(|error| ParseError {
-            error,
-            tag: T::tag(),
-        })?;
+        let uuid = Uuid::from_str(s)
+            .map_err(|error| ParseError::new::<T>(ParseErrorKind::classify(s), s, error))?;
         Ok(Self::from_untyped_uuid(uuid))
     }
 }
@@ -782,25 +1213,70 @@ impl<T: TypedUuidKind> From<TypedUuid<T>> for alloc::vec::Vec<u8> {
     }
 }
 
+/// The `x-rust-type` extension values for `TypedUuid`, used by typify and
+/// progenitor to replace generated types with `TypedUuid`.
+#[cfg(any(feature = "schemars08", feature = "utoipa5"))]
+mod rust_type {
+    use alloc::format;
+
+    pub(crate) const CRATE_NAME: &str = "newtype-uuid";
+    pub(crate) const CRATE_VERSION: &str = "1";
+    pub(crate) const CRATE_PATH: &str = "newtype_uuid::TypedUuid";
+
+    /// Given the `x-rust-type` extension for a kind, returns the extension for
+    /// `TypedUuid<Kind>`, pointing at `alias` in the same module as the kind.
+    ///
+    /// Returns `None` if the crate, version or path is missing.
+    // ? on Option is too easy to make mistakes with, so we use `let Some(..) =
+    // .. else` instead.
+    #[allow(clippy::question_mark)]
+    pub(crate) fn lift_to_alias(
+        kind_rust_type: &serde_json::Value,
+        alias: &str,
+    ) -> Option<serde_json::Value> {
+        // The crate, version and path must all be present.
+        let Some(crate_) = kind_rust_type.get("crate") else {
+            return None;
+        };
+        let Some(version) = kind_rust_type.get("version") else {
+            return None;
+        };
+        let Some(path) = kind_rust_type.get("path").and_then(|p| p.as_str()) else {
+            return None;
+        };
+        let Some((module_path, _)) = path.rsplit_once("::") else {
+            return None;
+        };
+
+        // The preconditions are all met. We can lift the schema by appending
+        // the alias to the module path.
+        let alias_path = format!("{module_path}::{alias}");
+
+        Some(serde_json::json!({
+            "crate": crate_,
+            "version": version,
+            "path": alias_path,
+        }))
+    }
+}
+
 #[cfg(feature = "schemars08")]
 mod schemars08_imp {
     use super::*;
+    use crate::rust_type::{CRATE_NAME, CRATE_PATH, CRATE_VERSION};
     use schemars::{
         JsonSchema, SchemaGenerator,
-        schema::{InstanceType, Schema, SchemaObject},
+        schema::{InstanceType, Metadata, Schema, SchemaObject},
         schema_for,
     };
 
-    const CRATE_NAME: &str = "newtype-uuid";
-    const CRATE_VERSION: &str = "1";
-    const CRATE_PATH: &str = "newtype_uuid::TypedUuid";
-
     /// Implements `JsonSchema` for `TypedUuid<T>`, if `T` implements `JsonSchema`.
     ///
     /// * `schema_name` is set to `"TypedUuidFor"`, concatenated by the schema name of `T`.
     /// * `schema_id` is set to `format!("newtype_uuid::TypedUuid<{}>", T::schema_id())`.
     /// * `json_schema` is the same as the one for `Uuid`, with the `x-rust-type` extension
-    ///   to allow automatic replacement in typify and progenitor.
+    ///   to allow automatic replacement in typify and progenitor. Any `examples` in the
+    ///   schema for `T` are carried over.
     impl<T> JsonSchema for TypedUuid<T>
     where
         T: TypedUuidKind + JsonSchema,
@@ -829,11 +1305,14 @@ mod schemars08_imp {
             // added to the list of schemas in `generator` in case the lifting
             // is successful.
             let t_schema = schema_for!(T);
-            if let Some(schema) = lift_json_schema(&t_schema.schema, T::alias()) {
+            let metadata = examples_metadata(&t_schema.schema);
+            if let Some(mut schema) = lift_json_schema(&t_schema.schema, T::alias()) {
+                schema.metadata = metadata;
                 return schema.into();
             }
 
             SchemaObject {
+                metadata,
                 instance_type: Some(InstanceType::String.into()),
                 format: Some("uuid".to_string()),
                 extensions: [(
@@ -853,6 +1332,20 @@ mod schemars08_imp {
         }
     }
 
+    /// Returns metadata holding the examples for `T`'s schema, if there are any.
+    ///
+    /// `impl_typed_uuid_kinds!` exports a kind's constants as examples.
+    fn examples_metadata(schema: &SchemaObject) -> Option<Box<Metadata>> {
+        let examples = &schema.metadata.as_ref()?.examples;
+        if examples.is_empty() {
+            return None;
+        }
+        Some(Box::new(Metadata {
+            examples: examples.clone(),
+            ..Default::default()
+        }))
+    }
+
     // ? on Option is too easy to make mistakes with, so we use `let Some(..) =
     // .. else` instead.
     #[allow(clippy::question_mark)]
@@ -860,131 +1353,1555 @@ mod schemars08_imp {
         let Some(alias) = alias else {
             return None;
         };
-
         let Some(v) = schema.extensions.get("x-rust-type") else {
             return None;
         };
-
-        // The crate, version and path must all be present.
-        let Some(crate_) = v.get("crate") else {
-            return None;
-        };
-        let Some(version) = v.get("version") else {
-            return None;
-        };
-        let Some(path) = v.get("path").and_then(|p| p.as_str()) else {
-            return None;
-        };
-        let Some((module_path, _)) = path.rsplit_once("::") else {
+        let Some(rust_type) = crate::rust_type::lift_to_alias(v, alias) else {
             return None;
         };
 
-        // The preconditions are all met. We can lift the schema by appending
-        // the alias to the module path.
-        let alias_path = format!("{module_path}::{alias}");
-
         Some(SchemaObject {
             instance_type: Some(InstanceType::String.into()),
             format: Some("uuid".to_string()),
-            extensions: [(
-                "x-rust-type".to_string(),
-                serde_json::json!({
-                    "crate": crate_,
-                    "version": version,
-                    "path": alias_path,
-                }),
-            )]
-            .into_iter()
-            .collect(),
+            extensions: [("x-rust-type".to_string(), rust_type)]
+                .into_iter()
+                .collect(),
             ..Default::default()
         })
     }
 }
 
-#[cfg(feature = "proptest1")]
-mod proptest1_imp {
+#[cfg(feature = "utoipa5")]
+mod utoipa5_imp {
     use super::*;
-    use proptest::{
-        arbitrary::{Arbitrary, any},
-        strategy::{BoxedStrategy, Strategy},
+    use crate::rust_type::{CRATE_NAME, CRATE_PATH, CRATE_VERSION};
+    use alloc::{borrow::Cow, format, string::String, vec::Vec};
+    use utoipa::{
+        PartialSchema, ToSchema,
+        openapi::{
+            KnownFormat, ObjectBuilder, RefOr, Schema, SchemaFormat, Type,
+            extensions::ExtensionsBuilder, schema::Object,
+        },
     };
 
-    /// Parameters for use with `proptest` instances.
-    ///
-    /// This is currently not exported as a type because it has no options. But
-    /// it's left in as an extension point for the future.
-    #[derive(Clone, Debug, Default)]
-    pub struct TypedUuidParams(());
-
-    /// Generates random `TypedUuid<T>` instances.
+    /// Implements `PartialSchema` for `TypedUuid<T>`, if `T` implements `ToSchema`.
     ///
-    /// Currently, this always returns a version 4 UUID. Support for other kinds
-    /// of UUIDs might be added via [`Self::Parameters`] in the future.
-    impl<T> Arbitrary for TypedUuid<T>
+    /// The schema is the same as the one for `Uuid`, with the `x-rust-type` extension to allow
+    /// automatic replacement in typify and progenitor.
+    impl<T> PartialSchema for TypedUuid<T>
     where
-        T: TypedUuidKind,
+        T: TypedUuidKind + ToSchema,
     {
-        type Parameters = TypedUuidParams;
-        type Strategy = BoxedStrategy<Self>;
+        fn schema() -> RefOr<Schema> {
+            // As with schemars, if `T`'s schema has `x-rust-type` and an alias
+            // is available, lift the `x-rust-type` into our own schema.
+            if let Some(schema) = lift_schema(&T::schema(), T::alias()) {
+                return schema.into();
+            }
 
-        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
-            let bytes = any::<[u8; 16]>();
-            bytes
-                .prop_map(|b| {
-                    let uuid = uuid::Builder::from_random_bytes(b).into_uuid();
-                    TypedUuid::<T>::from_untyped_uuid(uuid)
-                })
-                .boxed()
+            uuid_schema(serde_json::json!({
+                "crate": CRATE_NAME,
+                "version": CRATE_VERSION,
+                "path": CRATE_PATH,
+                "parameters": [{
+                    "$ref": format!("#/components/schemas/{}", T::name()),
+                }],
+            }))
+            .into()
         }
     }
-}
 
-/// Represents marker types that can be used as a type parameter for [`TypedUuid`].
-///
-/// Generally, an implementation of this will be a zero-sized type that can never be constructed. An
-/// empty struct or enum works well for this.
-///
-/// # Implementations
-///
-/// If the `schemars08` feature is enabled, and [`JsonSchema`] is implemented for a kind `T`, then
-/// [`TypedUuid`]`<T>` will also implement [`JsonSchema`].
-///
-/// If you have a large number of UUID kinds, consider using
-/// [`newtype-uuid-macros`] which comes with several convenience features.
-///
-/// ```
-/// use newtype_uuid_macros::impl_typed_uuid_kinds;
-///
-/// // Invoke this macro with:
-/// impl_typed_uuid_kinds! {
-///     kinds = {
-///         User = {},
-///         Project = {},
-///         // ...
-///     },
-/// }
-/// ```
-///
-/// See [`newtype-uuid-macros`] for more information.
-///
-/// [`newtype-uuid-macros`]: https://docs.rs/newtype-uuid-macros
-/// [`JsonSchema`]: schemars::JsonSchema
-pub trait TypedUuidKind: Send + Sync + 'static {
-    /// Returns the corresponding tag for this kind.
+    /// Implements `ToSchema` for `TypedUuid<T>`, if `T` implements `ToSchema`.
     ///
-    /// The tag forms a runtime representation of this type.
+    /// Within `#[derive(ToSchema)]` types, refer to `TypedUuid<T>` through a type alias (such as
+    /// the ones generated by `impl_typed_uuid_kinds!`): utoipa's derive macro handles types with
+    /// generic parameters differently.
     ///
-    /// The tag is required to be a static string.
-    fn tag() -> TypedUuidTag;
+    /// * `name` is set to the alias for `T` if available, and otherwise to `"TypedUuidFor"`,
+    ///   concatenated by the name of `T`.
+    /// * The schema for `T` is only registered if it's referred to, i.e. if the `x-rust-type`
+    ///   extension couldn't be lifted.
+    impl<T> ToSchema for TypedUuid<T>
+    where
+        T: TypedUuidKind + ToSchema,
+    {
+        fn name() -> Cow<'static, str> {
+            // Use the alias if available, otherwise generate our own schema name.
+            if let Some(alias) = T::alias() {
+                Cow::Borrowed(alias)
+            } else {
+                Cow::Owned(format!("TypedUuidFor{}", T::name()))
+            }
+        }
 
-    /// Returns a string that corresponds to a type alias for `TypedUuid<Self>`,
-    /// if one is defined.
-    ///
-    /// The type alias must be defined in the same module as `Self`. This
-    /// function is used by the schemars integration to refer to embed a
-    /// reference to that alias in the schema, if available.
-    ///
-    /// This is usually defined by the [`newtype-uuid-macros`] crate.
+        fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+            let t_schema = T::schema();
+            if lift_schema(&t_schema, T::alias()).is_none() {
+                schemas.push((T::name().into_owned(), t_schema));
+                T::schemas(schemas);
+            }
+        }
+    }
+
+    fn uuid_schema(rust_type: serde_json::Value) -> Object {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Uuid)))
+            .extensions(Some(
+                ExtensionsBuilder::new()
+                    .add("x-rust-type", rust_type)
+                    .build(),
+            ))
+            .build()
+    }
+
+    // ? on Option is too easy to make mistakes with, so we use `let Some(..) =
+    // .. else` instead.
+    #[allow(clippy::question_mark)]
+    fn lift_schema(schema: &RefOr<Schema>, alias: Option<&str>) -> Option<Object> {
+        let Some(alias) = alias else {
+            return None;
+        };
+        let RefOr::T(Schema::Object(object)) = schema else {
+            return None;
+        };
+        let Some(v) = object
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("x-rust-type"))
+        else {
+            return None;
+        };
+        let Some(rust_type) = crate::rust_type::lift_to_alias(v, alias) else {
+            return None;
+        };
+
+        Some(uuid_schema(rust_type))
+    }
+}
+
+/// Names for `TypedUuid<T>` in other type systems, such as GraphQL and
+/// TypeScript.
+#[cfg(any(feature = "async-graphql7", feature = "ts-rs11"))]
+mod type_name {
+    use super::*;
+    use alloc::{borrow::Cow, string::String};
+
+    /// Returns the alias for `T` if available, and otherwise the tag converted
+    /// to PascalCase, followed by `Uuid`.
+    pub(crate) fn type_name<T: TypedUuidKind>() -> Cow<'static, str> {
+        if let Some(alias) = T::alias() {
+            return Cow::Borrowed(alias);
+        }
+
+        // Tags are validated to only contain ASCII letters, digits, underscores
+        // and hyphens, and to not start with a digit, so the result is always a
+        // valid identifier.
+        let tag = T::tag();
+        let mut name = String::with_capacity(tag.as_str().len() + 4);
+        for word in tag.as_str().split(['_', '-']) {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                name.push(first.to_ascii_uppercase());
+                name.push_str(chars.as_str());
+            }
+        }
+        name.push_str("Uuid");
+        Cow::Owned(name)
+    }
+}
+
+#[cfg(feature = "async-graphql7")]
+mod async_graphql7_imp {
+    use super::*;
+    use crate::type_name::type_name;
+    use alloc::borrow::Cow;
+    use async_graphql::{
+        Description, InputValueError, InputValueResult, Scalar, ScalarType, TypeName, Value,
+    };
+
+    /// Implements `ScalarType`, `InputType` and `OutputType` for `TypedUuid<T>`, if `T`
+    /// implements `Description`.
+    ///
+    /// Each kind is represented as a distinct string scalar, named by [`TypeName`] and described
+    /// by `T`'s description.
+    #[Scalar(
+        name_type,
+        use_type_description,
+        specified_by_url = "https://www.rfc-editor.org/rfc/rfc9562"
+    )]
+    impl<T> ScalarType for TypedUuid<T>
+    where
+        T: TypedUuidKind + Description,
+    {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) => s
+                    .parse()
+                    .map_err(|error: ParseError| InputValueError::custom(error)),
+                _ => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn is_valid(value: &Value) -> bool {
+            matches!(value, Value::String(s) if Uuid::parse_str(s).is_ok())
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.to_string())
+        }
+    }
+
+    /// Implements `TypeName` for `TypedUuid<T>`, if `T` implements `Description`.
+    ///
+    /// `type_name` is set to the alias for `T` if available, and otherwise to the tag converted
+    /// to PascalCase, followed by `Uuid` (e.g. `business_unit` becomes `BusinessUnitUuid`).
+    impl<T> TypeName for TypedUuid<T>
+    where
+        T: TypedUuidKind + Description,
+    {
+        fn type_name() -> Cow<'static, str> {
+            type_name::<T>()
+        }
+    }
+
+    /// Implements `Description` for `TypedUuid<T>` by forwarding to `T`.
+    impl<T> Description for TypedUuid<T>
+    where
+        T: TypedUuidKind + Description,
+    {
+        fn description() -> &'static str {
+            T::description()
+        }
+    }
+}
+
+#[cfg(feature = "ts-rs11")]
+mod ts_rs11_imp {
+    use super::*;
+    use crate::type_name::type_name;
+    use std::{format, path::PathBuf, string::String};
+    use ts_rs::TS;
+
+    /// Implements `TS` for `TypedUuid<T>` as a branded string type.
+    ///
+    /// For example, `TypedUuid<UserKind>` with the alias `UserUuid` and the tag `user` is declared
+    /// as:
+    ///
+    /// ```typescript
+    /// type UserUuid = string & { readonly __kind: "user" };
+    /// ```
+    ///
+    /// * `name` is set to the alias for `T` if available, and otherwise to the tag converted to
+    ///   PascalCase, followed by `Uuid`.
+    /// * The declaration is exported to `{name}.ts`, and imported from there by types that
+    ///   contain `TypedUuid<T>`.
+    impl<T: TypedUuidKind> TS for TypedUuid<T> {
+        type WithoutGenerics = Self;
+        type OptionInnerType = Self;
+
+        fn name() -> String {
+            type_name::<T>().into_owned()
+        }
+
+        fn inline() -> String {
+            // Tags can't contain characters that need escaping.
+            format!("string & {{ readonly __kind: \"{}\" }}", T::tag().as_str())
+        }
+
+        fn inline_flattened() -> String {
+            panic!("{} cannot be flattened", Self::name())
+        }
+
+        fn decl() -> String {
+            format!("type {} = {};", Self::name(), Self::inline())
+        }
+
+        fn decl_concrete() -> String {
+            Self::decl()
+        }
+
+        fn output_path() -> Option<PathBuf> {
+            Some(PathBuf::from(format!("{}.ts", Self::name())))
+        }
+    }
+}
+
+#[cfg(feature = "proptest1")]
+mod proptest1_imp {
+    use super::*;
+    use alloc::vec::Vec;
+    use core::ops::RangeInclusive;
+    use proptest::{
+        arbitrary::{Arbitrary, any},
+        sample::select,
+        strategy::{BoxedStrategy, Just, Strategy, Union},
+    };
+
+    /// Parameters for use with `proptest` instances.
+    ///
+    /// By default, this generates random version 4 UUIDs. Use the methods on
+    /// this type to generate other versions, to mix in the nil and max UUIDs,
+    /// or to draw from a fixed pool of UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use newtype_uuid::{
+    ///     TypedUuid, TypedUuidKind, TypedUuidParams, TypedUuidTag, UuidVersionParams,
+    ///     typed_uuid_strategy_with,
+    /// };
+    /// use proptest::prelude::*;
+    ///
+    /// enum MyKind {}
+    /// impl TypedUuidKind for MyKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("my_kind");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// // Generate a mix of v4 and v7 UUIDs (with timestamps in 2024), along
+    /// // with the occasional nil UUID.
+    /// let params = TypedUuidParams::new()
+    ///     .with_versions([
+    ///         UuidVersionParams::V4,
+    ///         UuidVersionParams::V7 {
+    ///             unix_millis: 1_704_067_200_000..=1_735_689_599_999,
+    ///         },
+    ///     ])
+    ///     .with_nil(true);
+    ///
+    /// proptest!(|(uuid in typed_uuid_strategy_with::<MyKind>(params))| {
+    ///     prop_assert!(uuid.is_nil() || matches!(uuid.get_version_num(), 4 | 7));
+    /// });
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct TypedUuidParams {
+        versions: Vec<UuidVersionParams>,
+        include_nil: bool,
+        include_max: bool,
+        pool: Option<Vec<Uuid>>,
+    }
+
+    impl Default for TypedUuidParams {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl TypedUuidParams {
+        /// Creates parameters that generate random version 4 UUIDs.
+        pub fn new() -> Self {
+            Self {
+                versions: alloc::vec![UuidVersionParams::V4],
+                include_nil: false,
+                include_max: false,
+                pool: None,
+            }
+        }
+
+        /// Sets the UUID versions to generate.
+        ///
+        /// Each generated UUID is drawn from one of these versions, chosen
+        /// uniformly at random.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `versions` is empty, or if a [`UuidVersionParams::V7`]
+        /// range extends past the largest 48-bit timestamp.
+        #[must_use]
+        pub fn with_versions(
+            mut self,
+            versions: impl IntoIterator<Item = UuidVersionParams>,
+        ) -> Self {
+            let versions: Vec<_> = versions.into_iter().collect();
+            assert!(
+                !versions.is_empty(),
+                "at least one version must be specified"
+            );
+            for version in &versions {
+                version.validate();
+            }
+            self.versions = versions;
+            self
+        }
+
+        /// Sets whether the nil UUID (all zeros) is occasionally generated.
+        #[must_use]
+        pub fn with_nil(mut self, include_nil: bool) -> Self {
+            self.include_nil = include_nil;
+            self
+        }
+
+        /// Sets whether the max UUID (all ones) is occasionally generated.
+        #[must_use]
+        pub fn with_max(mut self, include_max: bool) -> Self {
+            self.include_max = include_max;
+            self
+        }
+
+        /// Draws UUIDs from a fixed pool, rather than generating them.
+        ///
+        /// With a small pool, the same UUID will be generated repeatedly. This
+        /// is useful to force collisions when testing maps, deduplication, and
+        /// similar logic. If set, the versions specified via
+        /// [`Self::with_versions`] are ignored.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `pool` is empty.
+        #[must_use]
+        pub fn with_pool<U: GenericUuid>(mut self, pool: impl IntoIterator<Item = U>) -> Self {
+            let pool: Vec<_> = pool.into_iter().map(U::into_untyped_uuid).collect();
+            assert!(!pool.is_empty(), "pool must not be empty");
+            self.pool = Some(pool);
+            self
+        }
+
+        fn into_strategy(self) -> BoxedStrategy<Uuid> {
+            let base = match self.pool {
+                Some(pool) => select(pool).boxed(),
+                None => Union::new(self.versions.into_iter().map(|v| v.into_strategy())).boxed(),
+            };
+            if !self.include_nil && !self.include_max {
+                return base;
+            }
+
+            let mut options = alloc::vec![(8, base)];
+            if self.include_nil {
+                options.push((1, Just(Uuid::nil()).boxed()));
+            }
+            if self.include_max {
+                options.push((1, Just(Uuid::max()).boxed()));
+            }
+            Union::new_weighted(options).boxed()
+        }
+    }
+
+    /// A UUID version to generate, as part of [`TypedUuidParams`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum UuidVersionParams {
+        /// Random (version 4) UUIDs.
+        V4,
+
+        /// Time-ordered (version 7) UUIDs.
+        V7 {
+            /// The range of Unix timestamps, in milliseconds, to generate
+            /// UUIDs within.
+            ///
+            /// The end of the range must fit within 48 bits.
+            unix_millis: RangeInclusive<u64>,
+        },
+    }
+
+    impl UuidVersionParams {
+        const MAX_V7_MILLIS: u64 = (1 << 48) - 1;
+
+        fn validate(&self) {
+            match self {
+                Self::V4 => {}
+                Self::V7 { unix_millis } => {
+                    assert!(
+                        *unix_millis.end() <= Self::MAX_V7_MILLIS,
+                        "v7 timestamp range {unix_millis:?} must fit within 48 bits",
+                    );
+                }
+            }
+        }
+
+        fn into_strategy(self) -> BoxedStrategy<Uuid> {
+            match self {
+                Self::V4 => any::<uuid::Bytes>()
+                    .prop_map(|b| uuid::Builder::from_random_bytes(b).into_uuid())
+                    .boxed(),
+                Self::V7 { unix_millis } => (unix_millis, any::<[u8; 10]>())
+                    .prop_map(|(millis, b)| {
+                        uuid::Builder::from_unix_timestamp_millis(millis, &b).into_uuid()
+                    })
+                    .boxed(),
+            }
+        }
+    }
+
+    /// Generates random `TypedUuid<T>` instances.
+    ///
+    /// By default, this generates version 4 UUIDs. Other versions, along with
+    /// the nil and max UUIDs, can be generated by passing in
+    /// [`TypedUuidParams`] via `any_with`.
+    impl<T> Arbitrary for TypedUuid<T>
+    where
+        T: TypedUuidKind,
+    {
+        type Parameters = TypedUuidParams;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+            params
+                .into_strategy()
+                .prop_map(TypedUuid::<T>::from_untyped_uuid)
+                .boxed()
+        }
+    }
+
+    /// Returns a strategy that generates random version 4 `TypedUuid<T>`
+    /// instances.
+    ///
+    /// This is equivalent to `any::<TypedUuid<T>>()`.
+    pub fn typed_uuid_strategy<T: TypedUuidKind>() -> BoxedStrategy<TypedUuid<T>> {
+        TypedUuid::<T>::arbitrary_with(TypedUuidParams::new())
+    }
+
+    /// Returns a strategy that generates `TypedUuid<T>` instances according to
+    /// `params`.
+    ///
+    /// This is equivalent to `any_with::<TypedUuid<T>>(params)`.
+    pub fn typed_uuid_strategy_with<T: TypedUuidKind>(
+        params: TypedUuidParams,
+    ) -> BoxedStrategy<TypedUuid<T>> {
+        TypedUuid::<T>::arbitrary_with(params)
+    }
+}
+
+#[cfg(feature = "proptest1")]
+pub use proptest1_imp::{
+    TypedUuidParams, UuidVersionParams, typed_uuid_strategy, typed_uuid_strategy_with,
+};
+
+/// UUID versions and variants that the fuzzing and property-testing
+/// implementations bias towards.
+#[cfg(any(feature = "arbitrary1", feature = "quickcheck1"))]
+mod edge_cases {
+    use uuid::{Variant, Version};
+
+    pub(crate) const VERSIONS: [Version; 8] = [
+        Version::Mac,
+        Version::Dce,
+        Version::Md5,
+        Version::Random,
+        Version::Sha1,
+        Version::SortMac,
+        Version::SortRand,
+        Version::Custom,
+    ];
+
+    pub(crate) const NON_RFC_VARIANTS: [Variant; 3] =
+        [Variant::NCS, Variant::Microsoft, Variant::Future];
+}
+
+#[cfg(feature = "arbitrary1")]
+mod arbitrary1_imp {
+    use super::*;
+    use crate::edge_cases::{NON_RFC_VARIANTS, VERSIONS};
+    use arbitrary::{Arbitrary, Result, Unstructured};
+    use uuid::{Builder, Variant};
+
+    /// Generates arbitrary `TypedUuid<T>` instances.
+    ///
+    /// Unlike the default `proptest` strategy, which generates v4 UUIDs, the
+    /// generated values are biased towards edge cases that parsers and
+    /// validators might get wrong:
+    ///
+    /// * the nil and max UUIDs,
+    /// * UUIDs of each version, with the RFC 9562 variant,
+    /// * UUIDs with a known version but a different (NCS, Microsoft, or
+    ///   future) variant, and
+    /// * completely arbitrary bits.
+    impl<'a, T: TypedUuidKind> Arbitrary<'a> for TypedUuid<T> {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            // Pick the shape first, so that the fuzzer can cheaply steer
+            // between them.
+            let shape = u.int_in_range(0..=15u8)?;
+            let uuid = match shape {
+                0 => Uuid::nil(),
+                1 => Uuid::max(),
+                2..=3 => Builder::from_bytes(u.arbitrary()?)
+                    .with_version(*u.choose(&VERSIONS)?)
+                    .with_variant(*u.choose(&NON_RFC_VARIANTS)?)
+                    .into_uuid(),
+                4..=7 => Uuid::from_bytes(u.arbitrary()?),
+                _ => Builder::from_bytes(u.arbitrary()?)
+                    .with_version(*u.choose(&VERSIONS)?)
+                    .with_variant(Variant::RFC4122)
+                    .into_uuid(),
+            };
+            Ok(Self::from_untyped_uuid(uuid))
+        }
+
+        #[inline]
+        fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+            // 1 byte for the shape, up to 16 bytes for the UUID, and up to 2
+            // more bytes for the version and variant choices.
+            (1, Some(19))
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck1")]
+mod quickcheck1_imp {
+    use super::*;
+    use crate::edge_cases::{NON_RFC_VARIANTS, VERSIONS};
+    use alloc::boxed::Box;
+    use quickcheck::{Arbitrary, Gen};
+    use uuid::{Builder, Variant};
+
+    /// Generates arbitrary `TypedUuid<T>` instances.
+    ///
+    /// As with the `arbitrary1` implementation, the generated values cover the
+    /// nil and max UUIDs, each UUID version (with both the RFC 9562 variant and
+    /// other variants), and completely arbitrary bits.
+    ///
+    /// Shrinking proceeds towards the nil UUID.
+    impl<T: TypedUuidKind> Arbitrary for TypedUuid<T> {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let bytes = u128::arbitrary(g).to_be_bytes();
+            let uuid = match u8::arbitrary(g) % 16 {
+                0 => Uuid::nil(),
+                1 => Uuid::max(),
+                2..=3 => Builder::from_bytes(bytes)
+                    .with_version(*g.choose(&VERSIONS).unwrap())
+                    .with_variant(*g.choose(&NON_RFC_VARIANTS).unwrap())
+                    .into_uuid(),
+                4..=7 => Uuid::from_bytes(bytes),
+                _ => Builder::from_bytes(bytes)
+                    .with_version(*g.choose(&VERSIONS).unwrap())
+                    .with_variant(Variant::RFC4122)
+                    .into_uuid(),
+            };
+            Self::from_untyped_uuid(uuid)
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            // The u128 shrinker tries 0 (the nil UUID) first, then values
+            // progressively closer to the original.
+            Box::new(self.as_u128().shrink().map(Self::from_u128))
+        }
+    }
+}
+
+#[cfg(feature = "clap4")]
+mod clap4_imp {
+    use super::*;
+    use alloc::{borrow::ToOwned, string::String};
+    use clap::{
+        Arg, Command, Id, ValueHint,
+        builder::{TypedValueParser, ValueParserFactory},
+        error::{Error, ErrorKind},
+    };
+    use core::marker::PhantomData;
+    use std::ffi::OsStr;
+
+    /// A clap value parser for [`TypedUuid<T>`].
+    ///
+    /// This is used automatically by clap's derive API for `TypedUuid<T>`
+    /// arguments. It accepts both plain UUIDs and UUIDs prefixed with the
+    /// kind's tag and a colon (for example, `user:67e55044-10b1-426f-9247-bb680e5fe0c8`),
+    /// and error messages mention the expected kind.
+    ///
+    /// clap can't derive the name shown in help text from the value parser. With
+    /// the builder API, use [`typed_uuid_arg`] to create an argument that shows
+    /// the kind there. With the derive API, pass [`Self::value_name`] to
+    /// `value_name`.
+    ///
+    /// UUIDs can't be enumerated, so this parser doesn't report any possible
+    /// values for shell completion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Parser;
+    /// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag, TypedUuidValueParser};
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// #[derive(Parser)]
+    /// struct Args {
+    ///     /// The user to look up.
+    ///     #[arg(
+    ///         long,
+    ///         value_name = TypedUuidValueParser::<UserKind>::value_name(),
+    ///         value_hint = clap::ValueHint::Other,
+    ///     )]
+    ///     user_id: TypedUuid<UserKind>,
+    /// }
+    ///
+    /// let args = Args::try_parse_from([
+    ///     "app",
+    ///     "--user-id",
+    ///     "user:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(
+    ///     args.user_id.to_string(),
+    ///     "67e55044-10b1-426f-9247-bb680e5fe0c8"
+    /// );
+    /// ```
+    pub struct TypedUuidValueParser<T> {
+        _phantom: PhantomData<fn() -> T>,
+    }
+
+    impl<T: TypedUuidKind> TypedUuidValueParser<T> {
+        /// Creates a new value parser.
+        pub fn new() -> Self {
+            Self {
+                _phantom: PhantomData,
+            }
+        }
+
+        /// Returns the value name to show in help text: the tag in upper case,
+        /// followed by `_UUID` (for example, `USER_UUID`).
+        pub fn value_name() -> String {
+            let mut name: String = T::tag()
+                .as_str()
+                .chars()
+                .map(|c| match c {
+                    '-' => '_',
+                    c => c.to_ascii_uppercase(),
+                })
+                .collect();
+            name.push_str("_UUID");
+            name
+        }
+
+        fn parse_str(value: &str) -> Result<TypedUuid<T>, String> {
+            let tag = T::tag();
+            if let Some(rest) = value
+                .strip_prefix(tag.as_str())
+                .and_then(|rest| rest.strip_prefix(':'))
+            {
+                return Uuid::parse_str(rest)
+                    .map(TypedUuid::from_untyped_uuid)
+                    .map_err(|error| format!("invalid {tag} UUID: {error}"));
+            }
+
+            match Uuid::parse_str(value) {
+                Ok(uuid) => Ok(TypedUuid::from_untyped_uuid(uuid)),
+                Err(error) => match value.split_once(':') {
+                    // Uuid::parse_str also accepts the urn:uuid: prefix, so
+                    // don't treat that as a mismatched tag.
+                    Some((other, _)) if other != "urn" => {
+                        Err(format!("expected tag `{tag}`, found `{other}`"))
+                    }
+                    _ => Err(format!(
+                        "expected a {tag} UUID, optionally prefixed with `{tag}:`: {error}"
+                    )),
+                },
+            }
+        }
+    }
+
+    impl<T> Clone for TypedUuidValueParser<T> {
+        fn clone(&self) -> Self {
+            Self {
+                _phantom: PhantomData,
+            }
+        }
+    }
+
+    impl<T: TypedUuidKind> Default for TypedUuidValueParser<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T: TypedUuidKind> fmt::Debug for TypedUuidValueParser<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("TypedUuidValueParser")
+                .field("tag", &T::tag())
+                .finish()
+        }
+    }
+
+    impl<T: TypedUuidKind> TypedValueParser for TypedUuidValueParser<T> {
+        type Value = TypedUuid<T>;
+
+        fn parse_ref(
+            &self,
+            cmd: &Command,
+            arg: Option<&Arg>,
+            value: &OsStr,
+        ) -> Result<Self::Value, Error> {
+            let arg = arg.map_or_else(|| "...".to_owned(), |arg| arg.to_string());
+            let Some(value) = value.to_str() else {
+                return Err(Error::raw(
+                    ErrorKind::InvalidUtf8,
+                    format!("invalid UTF-8 in value for '{arg}'"),
+                )
+                .format(&mut cmd.clone()));
+            };
+            Self::parse_str(value).map_err(|reason| {
+                Error::raw(
+                    ErrorKind::ValueValidation,
+                    format!("invalid value '{value}' for '{arg}': {reason}"),
+                )
+                .format(&mut cmd.clone())
+            })
+        }
+    }
+
+    impl<T: TypedUuidKind> ValueParserFactory for TypedUuid<T> {
+        type Parser = TypedUuidValueParser<T>;
+
+        fn value_parser() -> Self::Parser {
+            TypedUuidValueParser::new()
+        }
+    }
+
+    /// Creates a clap [`Arg`] that takes a [`TypedUuid<T>`].
+    ///
+    /// The argument uses [`TypedUuidValueParser`], shows the kind in help text
+    /// via [`TypedUuidValueParser::value_name`] (for example, `<USER_UUID>`),
+    /// and sets [`ValueHint::Other`] so that shell completion doesn't suggest
+    /// file names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use clap::Command;
+    /// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag, typed_uuid_arg};
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// let mut cmd = Command::new("app").arg(typed_uuid_arg::<UserKind>("user_id").long("user-id"));
+    /// assert!(
+    ///     cmd.render_help()
+    ///         .to_string()
+    ///         .contains("--user-id <USER_UUID>")
+    /// );
+    ///
+    /// let matches = cmd
+    ///     .try_get_matches_from([
+    ///         "app",
+    ///         "--user-id",
+    ///         "user:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     ])
+    ///     .unwrap();
+    /// let user_id = matches.get_one::<TypedUuid<UserKind>>("user_id").unwrap();
+    /// assert_eq!(user_id.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// ```
+    pub fn typed_uuid_arg<T: TypedUuidKind>(id: impl Into<Id>) -> Arg {
+        Arg::new(id)
+            .value_parser(TypedUuidValueParser::<T>::new())
+            .value_name(TypedUuidValueParser::<T>::value_name())
+            .value_hint(ValueHint::Other)
+    }
+}
+
+#[cfg(feature = "clap4")]
+pub use clap4_imp::{TypedUuidValueParser, typed_uuid_arg};
+
+#[cfg(feature = "valuable01")]
+mod valuable01_imp {
+    use super::*;
+    use valuable::{
+        Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit,
+    };
+
+    static FIELDS: &[NamedField<'static>] = &[NamedField::new("kind"), NamedField::new("uuid")];
+
+    /// Exposes a `TypedUuid<T>` as a struct with two fields: `kind`, the tag
+    /// of the UUID, and `uuid`, the hyphenated UUID string.
+    impl<T: TypedUuidKind> Valuable for TypedUuid<T> {
+        fn as_value(&self) -> Value<'_> {
+            Value::Structable(self)
+        }
+
+        fn visit(&self, visit: &mut dyn Visit) {
+            let mut buf = Uuid::encode_buffer();
+            let uuid = self.uuid.hyphenated().encode_lower(&mut buf);
+            visit.visit_named_fields(&NamedValues::new(
+                FIELDS,
+                &[Value::String(T::tag().as_str()), Value::String(uuid)],
+            ));
+        }
+    }
+
+    impl<T: TypedUuidKind> Structable for TypedUuid<T> {
+        fn definition(&self) -> StructDef<'_> {
+            StructDef::new_static("TypedUuid", Fields::Named(FIELDS))
+        }
+    }
+}
+
+#[cfg(feature = "tracing01")]
+mod tracing01_imp {
+    use super::*;
+
+    impl<T: TypedUuidKind> TypedUuid<T> {
+        /// Returns a value that records this UUID along with its kind, for
+        /// use as a `tracing` span or event field.
+        ///
+        /// If the `valuable01` feature is enabled and the build uses
+        /// `--cfg tracing_unstable`, the UUID is recorded as a structured
+        /// value with separate `kind` and `uuid` fields. Otherwise, it is
+        /// recorded as a string of the form `kind:uuid`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+        ///
+        /// enum UserKind {}
+        /// impl TypedUuidKind for UserKind {
+        ///     fn tag() -> TypedUuidTag {
+        ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+        ///         TAG
+        ///     }
+        /// }
+        ///
+        /// let user_id = TypedUuid::<UserKind>::nil();
+        /// // Records `user_id=user:00000000-0000-0000-0000-000000000000`.
+        /// tracing::info!(user_id = user_id.as_tracing_value(), "user logged in");
+        /// ```
+        pub fn as_tracing_value(&self) -> impl tracing::Value + '_ {
+            #[cfg(all(tracing_unstable, feature = "valuable01"))]
+            let value = valuable::Valuable::as_value(self);
+            #[cfg(not(all(tracing_unstable, feature = "valuable01")))]
+            let value = tracing::field::display(Tagged(self));
+            value
+        }
+    }
+
+    #[cfg(not(all(tracing_unstable, feature = "valuable01")))]
+    struct Tagged<'a, T: TypedUuidKind>(&'a TypedUuid<T>);
+
+    #[cfg(not(all(tracing_unstable, feature = "valuable01")))]
+    impl<T: TypedUuidKind> fmt::Display for Tagged<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}:{}", T::tag(), self.0)
+        }
+    }
+}
+
+#[cfg(feature = "defmt1")]
+mod defmt1_imp {
+    use super::*;
+    use defmt::{Format, Formatter, Str};
+
+    /// A [`TypedUuidKind`] with a tag interned by defmt.
+    ///
+    /// [`TypedUuid<T>`] implements [`defmt::Format`] if `T` implements this trait. defmt can only
+    /// intern string literals, so this is usually implemented by [`newtype-uuid-macros`] with the
+    /// `defmt1` setting. To implement it manually, return the tag from [`defmt::intern!`]:
+    ///
+    /// ```
+    /// use newtype_uuid::{DefmtKind, TypedUuidKind, TypedUuidTag};
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// impl DefmtKind for UserKind {
+    ///     fn defmt_tag() -> defmt::Str {
+    ///         defmt::intern!("user")
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`newtype-uuid-macros`]: https://docs.rs/newtype-uuid-macros
+    pub trait DefmtKind: TypedUuidKind {
+        /// Returns the tag for this kind, interned by defmt.
+        ///
+        /// This must be the same string as [`TypedUuidKind::tag`].
+        fn defmt_tag() -> Str;
+    }
+
+    /// Formats a `TypedUuid<T>` as `kind:uuid`.
+    ///
+    /// Only the index of the interned tag and the 16 bytes of the UUID are transmitted; the
+    /// hyphenated form is produced by the host.
+    impl<T: DefmtKind> Format for TypedUuid<T> {
+        fn format(&self, f: Formatter<'_>) {
+            let value = self.uuid.as_u128();
+            defmt::write!(
+                f,
+                "{=istr}:{=u32:08x}-{=u16:04x}-{=u16:04x}-{=u16:04x}-{=u16:04x}{=u32:08x}",
+                T::defmt_tag(),
+                (value >> 96) as u32,
+                (value >> 80) as u16,
+                (value >> 64) as u16,
+                (value >> 48) as u16,
+                (value >> 32) as u16,
+                value as u32,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "defmt1")]
+pub use defmt1_imp::DefmtKind;
+
+#[cfg(feature = "pyo3028")]
+mod pyo3028_imp {
+    use super::*;
+    use pyo3::{
+        Borrowed, Bound, FromPyObject, IntoPyObject, PyAny, PyErr, PyResult, Python,
+        exceptions::{PyTypeError, PyValueError},
+        types::{PyAnyMethods, PyString, PyStringMethods, PyTypeMethods},
+    };
+
+    /// Extracts a `TypedUuid<T>` from a Python `uuid.UUID`, or from a `str` containing a UUID.
+    ///
+    /// Error messages mention the expected kind: a `TypeError` is raised for other types, and a
+    /// `ValueError` for strings that aren't valid UUIDs.
+    impl<T: TypedUuidKind> FromPyObject<'_, '_> for TypedUuid<T> {
+        type Error = PyErr;
+
+        fn extract(obj: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+            if let Ok(s) = obj.cast::<PyString>() {
+                let s = s.to_cow()?;
+                return Uuid::parse_str(&s)
+                    .map(Self::from_untyped_uuid)
+                    .map_err(|error| {
+                        PyValueError::new_err(format!("invalid {} UUID {s:?}: {error}", T::tag()))
+                    });
+            }
+
+            match obj.extract::<Uuid>() {
+                Ok(uuid) => Ok(Self::from_untyped_uuid(uuid)),
+                Err(_) => {
+                    let ty = obj.get_type();
+                    let name = ty.name()?;
+                    Err(PyTypeError::new_err(format!(
+                        "expected a `uuid.UUID` or `str` for a {} UUID, found `{}`",
+                        T::tag(),
+                        name.to_cow()?,
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Converts a `TypedUuid<T>` into a Python `uuid.UUID`.
+    impl<'py, T: TypedUuidKind> IntoPyObject<'py> for TypedUuid<T> {
+        type Target = PyAny;
+        type Output = Bound<'py, Self::Target>;
+        type Error = PyErr;
+
+        fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+            self.uuid.into_pyobject(py)
+        }
+    }
+
+    /// Converts a `&TypedUuid<T>` into a Python `uuid.UUID`.
+    impl<'py, T: TypedUuidKind> IntoPyObject<'py> for &TypedUuid<T> {
+        type Target = PyAny;
+        type Output = Bound<'py, Self::Target>;
+        type Error = PyErr;
+
+        fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+            self.uuid.into_pyobject(py)
+        }
+    }
+}
+
+#[cfg(feature = "wasm-bindgen02")]
+#[allow(unsafe_code)]
+mod wasm_bindgen02_imp {
+    use super::*;
+    use std::{format, string::String};
+    use wasm_bindgen::{
+        JsError, JsValue,
+        convert::{FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi},
+        describe::WasmDescribe,
+    };
+
+    // Typed UUIDs are only passed by value. `RefFromWasmAbi` (for
+    // `&TypedUuid<T>` arguments) and the vector ABI traits (for
+    // `Vec<TypedUuid<T>>`) aren't implemented.
+
+    /// `TypedUuid<T>` crosses the JavaScript boundary as a string.
+    impl<T: TypedUuidKind> WasmDescribe for TypedUuid<T> {
+        fn describe() {
+            <String as WasmDescribe>::describe()
+        }
+    }
+
+    impl<T: TypedUuidKind> IntoWasmAbi for TypedUuid<T> {
+        type Abi = <String as IntoWasmAbi>::Abi;
+
+        #[inline]
+        fn into_abi(self) -> Self::Abi {
+            self.to_string().into_abi()
+        }
+    }
+
+    impl<T: TypedUuidKind> OptionIntoWasmAbi for TypedUuid<T> {
+        #[inline]
+        fn none() -> Self::Abi {
+            <String as OptionIntoWasmAbi>::none()
+        }
+    }
+
+    /// Parses a `TypedUuid<T>` from a JavaScript string.
+    ///
+    /// If the string isn't a valid UUID, a JavaScript `Error` mentioning the
+    /// expected kind is thrown, and the Rust function isn't called. To handle
+    /// invalid values instead, take a `JsValue` and convert it with
+    /// `TryFrom`.
+    impl<T: TypedUuidKind> FromWasmAbi for TypedUuid<T> {
+        type Abi = <String as FromWasmAbi>::Abi;
+
+        #[inline]
+        unsafe fn from_abi(js: Self::Abi) -> Self {
+            // SAFETY: the caller guarantees that `js` is a valid ABI value,
+            // which is the same as that for `String`.
+            let s = unsafe { String::from_abi(js) };
+            match Uuid::parse_str(&s) {
+                Ok(uuid) => Self::from_untyped_uuid(uuid),
+                Err(error) => {
+                    wasm_bindgen::throw_str(&format!("invalid {} UUID {s:?}: {error}", T::tag()))
+                }
+            }
+        }
+    }
+
+    impl<T: TypedUuidKind> OptionFromWasmAbi for TypedUuid<T> {
+        #[inline]
+        fn is_none(abi: &Self::Abi) -> bool {
+            <String as OptionFromWasmAbi>::is_none(abi)
+        }
+    }
+
+    impl<T: TypedUuidKind> From<TypedUuid<T>> for JsValue {
+        #[inline]
+        fn from(value: TypedUuid<T>) -> Self {
+            JsValue::from_str(&value.to_string())
+        }
+    }
+
+    /// Parses a `TypedUuid<T>` from a JavaScript string.
+    ///
+    /// If the value isn't a string containing a valid UUID, the error mentions
+    /// the expected kind. Unlike taking a `TypedUuid<T>` argument directly,
+    /// this lets exported functions handle invalid values, or return
+    /// `Result<_, JsError>` to throw the error back to JavaScript:
+    ///
+    /// ```
+    /// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+    /// use wasm_bindgen::prelude::*;
+    ///
+    /// enum UserKind {}
+    /// impl TypedUuidKind for UserKind {
+    ///     fn tag() -> TypedUuidTag {
+    ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+    ///         TAG
+    ///     }
+    /// }
+    ///
+    /// #[wasm_bindgen]
+    /// pub fn user_url(id: JsValue) -> Result<String, JsError> {
+    ///     let id = TypedUuid::<UserKind>::try_from(id)?;
+    ///     Ok(format!("/users/{id}"))
+    /// }
+    /// ```
+    impl<T: TypedUuidKind> TryFrom<JsValue> for TypedUuid<T> {
+        type Error = JsError;
+
+        fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+            let Some(s) = value.as_string() else {
+                return Err(JsError::new(&format!(
+                    "expected a {} UUID string, found {value:?}",
+                    T::tag(),
+                )));
+            };
+            Uuid::parse_str(&s)
+                .map(Self::from_untyped_uuid)
+                .map_err(|error| JsError::new(&format!("invalid {} UUID {s:?}: {error}", T::tag())))
+        }
+    }
+}
+
+#[cfg(feature = "borsh1")]
+mod borsh1_imp {
+    use super::*;
+    use borsh::{
+        BorshDeserialize, BorshSerialize,
+        io::{Read, Result, Write},
+    };
+
+    /// Serializes a `TypedUuid<T>` as its 16 bytes, in big-endian order.
+    ///
+    /// This is the same encoding `uuid` uses for `Uuid` with its `borsh`
+    /// feature enabled.
+    impl<T: TypedUuidKind> BorshSerialize for TypedUuid<T> {
+        #[inline]
+        fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+            writer.write_all(self.as_bytes())
+        }
+    }
+
+    impl<T: TypedUuidKind> BorshDeserialize for TypedUuid<T> {
+        #[inline]
+        fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+            let bytes = uuid::Bytes::deserialize_reader(reader)?;
+            Ok(Self::from_bytes(bytes))
+        }
+    }
+}
+
+#[cfg(feature = "bincode2")]
+mod bincode2_imp {
+    use super::*;
+    use bincode2::{
+        BorrowDecode, Decode, Encode,
+        de::{BorrowDecoder, Decoder},
+        enc::Encoder,
+        error::{DecodeError, EncodeError},
+    };
+
+    /// Encodes a `TypedUuid<T>` as its 16 bytes, in big-endian order.
+    ///
+    /// Unlike slices, fixed-size arrays are encoded by bincode without a length
+    /// prefix, so this is exactly 16 bytes regardless of configuration.
+    impl<T: TypedUuidKind> Encode for TypedUuid<T> {
+        #[inline]
+        fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+            self.as_bytes().encode(encoder)
+        }
+    }
+
+    impl<T: TypedUuidKind, Context> Decode<Context> for TypedUuid<T> {
+        #[inline]
+        fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+            let bytes = uuid::Bytes::decode(decoder)?;
+            Ok(Self::from_bytes(bytes))
+        }
+    }
+
+    impl<'de, T: TypedUuidKind, Context> BorrowDecode<'de, Context> for TypedUuid<T> {
+        #[inline]
+        fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+            decoder: &mut D,
+        ) -> Result<Self, DecodeError> {
+            Self::decode(decoder)
+        }
+    }
+}
+
+#[cfg(feature = "rkyv08")]
+mod rkyv08_imp {
+    use super::*;
+
+    // The archived form is derived on `TypedUuid` itself. Since `Uuid` archives
+    // as itself, `ArchivedTypedUuid<T>` holds a plain `Uuid` and the impls
+    // below mirror those on `TypedUuid<T>`, so that archived values can be
+    // compared against and looked up with native ones.
+    //
+    // Comparisons are only implemented with the archived value on the left.
+    // A second `PartialEq` impl on `TypedUuid<T>` itself would break type
+    // inference in existing code such as `uuid == s.parse().unwrap()`.
+
+    impl<T: TypedUuidKind> ArchivedTypedUuid<T> {
+        /// Converts the archived UUID back into a [`TypedUuid`].
+        #[inline]
+        #[must_use]
+        pub const fn to_typed_uuid(&self) -> TypedUuid<T> {
+            TypedUuid::from_u128(self.uuid.as_u128())
+        }
+
+        /// Returns the inner [`Uuid`].
+        #[inline]
+        pub const fn as_untyped_uuid(&self) -> &Uuid {
+            &self.uuid
+        }
+    }
+
+    impl<T: TypedUuidKind> From<&ArchivedTypedUuid<T>> for TypedUuid<T> {
+        #[inline]
+        fn from(archived: &ArchivedTypedUuid<T>) -> Self {
+            archived.to_typed_uuid()
+        }
+    }
+
+    impl<T: TypedUuidKind> PartialEq for ArchivedTypedUuid<T> {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.uuid.eq(&other.uuid)
+        }
+    }
+
+    impl<T: TypedUuidKind> Eq for ArchivedTypedUuid<T> {}
+
+    impl<T: TypedUuidKind> PartialEq<TypedUuid<T>> for ArchivedTypedUuid<T> {
+        #[inline]
+        fn eq(&self, other: &TypedUuid<T>) -> bool {
+            self.uuid.eq(&other.uuid)
+        }
+    }
+
+    impl<T: TypedUuidKind> PartialOrd for ArchivedTypedUuid<T> {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<T: TypedUuidKind> Ord for ArchivedTypedUuid<T> {
+        #[inline]
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.uuid.cmp(&other.uuid)
+        }
+    }
+
+    impl<T: TypedUuidKind> PartialOrd<TypedUuid<T>> for ArchivedTypedUuid<T> {
+        #[inline]
+        fn partial_cmp(&self, other: &TypedUuid<T>) -> Option<Ordering> {
+            Some(self.uuid.cmp(&other.uuid))
+        }
+    }
+
+    /// Hashes the same way as [`TypedUuid`], so a `TypedUuid<T>` can be used to
+    /// look up keys in archived hash maps.
+    impl<T: TypedUuidKind> Hash for ArchivedTypedUuid<T> {
+        #[inline]
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.uuid.hash(state);
+        }
+    }
+
+    impl<T: TypedUuidKind> fmt::Debug for ArchivedTypedUuid<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.uuid.fmt(f)?;
+            write!(f, " ({})", T::tag())
+        }
+    }
+
+    impl<T: TypedUuidKind> fmt::Display for ArchivedTypedUuid<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.uuid.fmt(f)
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck1")]
+#[allow(unsafe_code)]
+mod bytemuck1_imp {
+    use super::*;
+
+    // bytemuck's derive for `Pod` requires every type parameter to be `Pod`,
+    // which kinds (usually uninhabited enums) never are. The only thing the
+    // kind contributes to the layout is a `PhantomData`, so implement it by
+    // hand instead.
+    //
+    // SAFETY: `TypedUuid<T>` is `#[repr(transparent)]` over `Uuid`, which is
+    // `Pod`; its only other field is a zero-sized `PhantomData<T>`. So it has
+    // no padding and every bit pattern is valid, whatever `T` is. It is `Copy`
+    // and `Zeroable` for all `T`, and `'static` since `TypedUuidKind` is.
+    unsafe impl<T: TypedUuidKind> bytemuck::Pod for TypedUuid<T> {}
+}
+
+#[cfg(feature = "miette7")]
+mod miette7_imp {
+    use super::*;
+    use alloc::{boxed::Box, format, string::ToString};
+    use miette::{Diagnostic, LabeledSpan, SourceSpan};
+
+    impl<T: TypedUuidKind> TypedUuid<T> {
+        /// Parses a UUID of this kind, recording `span` as the input's
+        /// location within a larger source, such as a configuration file.
+        ///
+        /// `span` should cover the UUID itself, excluding any surrounding
+        /// quotes. On failure, the span is stored in the returned
+        /// [`ParseError`], and the error's [`Diagnostic`] implementation
+        /// labels it -- or, for an invalid character, just that character.
+        /// Attach the source with [`miette::Report::with_source_code`] to
+        /// render it.
+        ///
+        /// To add a span to errors from other parsing methods, use
+        /// [`ParseError::with_span`] or [`CheckedParseError::with_span`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+        ///
+        /// enum UserKind {}
+        /// impl TypedUuidKind for UserKind {
+        ///     fn tag() -> TypedUuidTag {
+        ///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+        ///         TAG
+        ///     }
+        /// }
+        ///
+        /// let source = r#"owner = "dffc3068-1cd6-47d5-b2f3-636b41b0708x""#;
+        /// let start = source.find('"').unwrap() + 1;
+        /// let input = &source[start..source.len() - 1];
+        ///
+        /// let error = TypedUuid::<UserKind>::parse_spanned(input, (start, input.len())).unwrap_err();
+        /// assert_eq!(error.span(), Some((start, input.len()).into()));
+        ///
+        /// let report = miette::Report::new(error).with_source_code(source);
+        /// ```
+        pub fn parse_spanned(input: &str, span: impl Into<SourceSpan>) -> Result<Self, ParseError> {
+            input
+                .parse()
+                .map_err(|error: ParseError| error.with_span(span))
+        }
+    }
+
+    impl ParseError {
+        /// Records `span` as the location of the input within a larger source.
+        ///
+        /// See [`TypedUuid::parse_spanned`] for details.
+        #[must_use]
+        pub fn with_span(mut self, span: impl Into<SourceSpan>) -> Self {
+            self.span = Some(span.into());
+            self
+        }
+
+        /// Returns the location of the input within a larger source, if known.
+        ///
+        /// This is set by [`TypedUuid::parse_spanned`] and
+        /// [`ParseError::with_span`].
+        #[inline]
+        pub fn span(&self) -> Option<SourceSpan> {
+            self.span
+        }
+    }
+
+    impl CheckedParseError {
+        /// Records `span` as the location of the input within a larger source,
+        /// if the input isn't a valid UUID.
+        ///
+        /// See [`ParseError::with_span`].
+        #[must_use]
+        pub fn with_span(self, span: impl Into<SourceSpan>) -> Self {
+            match self {
+                Self::Parse(error) => Self::Parse(error.with_span(span)),
+                error => error,
+            }
+        }
+    }
+
+    /// Reports `ParseError`s with:
+    ///
+    /// * the code `newtype_uuid::parse_error`.
+    /// * help text naming the expected kind.
+    /// * if the input's span is known, a label on the input, or on the invalid
+    ///   character for [`ParseErrorKind::InvalidChar`].
+    impl Diagnostic for ParseError {
+        fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+            Some(Box::new("newtype_uuid::parse_error"))
+        }
+
+        fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+            Some(Box::new(format!(
+                "expected a {} UUID, such as `00000000-0000-0000-0000-000000000000`",
+                self.tag,
+            )))
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            let span = self.span?;
+            let label = match self.kind {
+                ParseErrorKind::InvalidChar { character, index } => LabeledSpan::new(
+                    Some(format!("invalid character {character:?}")),
+                    span.offset() + index,
+                    character.len_utf8(),
+                ),
+                kind => LabeledSpan::new_with_span(Some(kind.to_string()), span),
+            };
+            Some(Box::new(core::iter::once(label)))
+        }
+    }
+
+    /// Reports `CheckedParseError`s like [`ParseError`]s, with help text
+    /// naming the failed check.
+    impl Diagnostic for CheckedParseError {
+        fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+            match self {
+                Self::Parse(error) => error.code(),
+                _ => Some(Box::new("newtype_uuid::parse_error")),
+            }
+        }
+
+        fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+            let help = match self {
+                Self::Parse(error) => return error.help(),
+                Self::TagMismatch { tag } => {
+                    format!("expected a {tag} UUID prefixed with `{tag}:`")
+                }
+                Self::WrongVersion { tag, expected, .. } => {
+                    format!("expected a version {expected} {tag} UUID")
+                }
+                Self::NilDisallowed { tag } => format!("expected a non-nil {tag} UUID"),
+            };
+            Some(Box::new(help))
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            match self {
+                Self::Parse(error) => error.labels(),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Represents marker types that can be used as a type parameter for [`TypedUuid`].
+///
+/// Generally, an implementation of this will be a zero-sized type that can never be constructed. An
+/// empty struct or enum works well for this.
+///
+/// # Implementations
+///
+/// If the `schemars08` feature is enabled, and [`JsonSchema`] is implemented for a kind `T`, then
+/// [`TypedUuid`]`<T>` will also implement [`JsonSchema`].
+///
+/// If you have a large number of UUID kinds, consider using
+/// [`newtype-uuid-macros`] which comes with several convenience features.
+///
+/// ```
+/// use newtype_uuid_macros::impl_typed_uuid_kinds;
+///
+/// // Invoke this macro with:
+/// impl_typed_uuid_kinds! {
+///     kinds = {
+///         User = {},
+///         Project = {},
+///         // ...
+///     },
+/// }
+/// ```
+///
+/// See [`newtype-uuid-macros`] for more information.
+///
+/// [`newtype-uuid-macros`]: https://docs.rs/newtype-uuid-macros
+/// [`JsonSchema`]: schemars::JsonSchema
+pub trait TypedUuidKind: Send + Sync + 'static {
+    /// Returns the corresponding tag for this kind.
+    ///
+    /// The tag forms a runtime representation of this type.
+    ///
+    /// The tag is required to be a static string.
+    fn tag() -> TypedUuidTag;
+
+    /// Returns a string that corresponds to a type alias for `TypedUuid<Self>`,
+    /// if one is defined.
+    ///
+    /// The type alias must be defined in the same module as `Self`. This
+    /// function is used by the schemars integration to refer to embed a
+    /// reference to that alias in the schema, if available.
+    ///
+    /// This is usually defined by the [`newtype-uuid-macros`] crate.
     ///
     /// [`newtype-uuid-macros`]: https://docs.rs/newtype-uuid-macros
     #[inline]
@@ -1041,6 +2958,14 @@ impl TypedUuidTag {
     }
 
     const fn try_new_impl(tag: &'static str) -> Result<Self, &'static str> {
+        match Self::validate(tag) {
+            Ok(()) => Ok(Self(tag)),
+            Err(message) => Err(message),
+        }
+    }
+
+    /// Checks that `tag` is a valid tag, without requiring it to be static.
+    pub(crate) const fn validate(tag: &str) -> Result<(), &'static str> {
         if tag.is_empty() {
             return Err("tag must not be empty");
         }
@@ -1065,7 +2990,7 @@ impl TypedUuidTag {
             return Err("tag must only contain ASCII letters, digits, underscores, or hyphens");
         }
 
-        Ok(Self(tag))
+        Ok(())
     }
 
     /// Returns the tag as a string.
@@ -1118,11 +3043,69 @@ pub struct ParseError {
 
     /// The tag of the UUID that failed to parse.
     pub tag: TypedUuidTag,
+
+    kind: ParseErrorKind,
+
+    #[cfg(feature = "alloc")]
+    input: alloc::string::String,
+
+    #[cfg(feature = "miette7")]
+    span: Option<miette::SourceSpan>,
+}
+
+impl ParseError {
+    /// The maximum length of [`input`](Self::input) before it's truncated.
+    #[cfg(feature = "alloc")]
+    const MAX_INPUT_LEN: usize = 64;
+
+    fn new<T: TypedUuidKind>(
+        kind: ParseErrorKind,
+        #[cfg_attr(not(feature = "alloc"), expect(unused_variables))] input: &str,
+        error: uuid::Error,
+    ) -> Self {
+        Self {
+            error,
+            tag: T::tag(),
+            kind,
+            #[cfg(feature = "alloc")]
+            input: if input.len() > Self::MAX_INPUT_LEN {
+                let mut end = Self::MAX_INPUT_LEN;
+                while !input.is_char_boundary(end) {
+                    end -= 1;
+                }
+                alloc::format!("{}...", &input[..end])
+            } else {
+                input.into()
+            },
+            #[cfg(feature = "miette7")]
+            span: None,
+        }
+    }
+
+    /// Returns what was wrong with the input.
+    #[inline]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the input that failed to parse.
+    ///
+    /// Inputs longer than 64 bytes are truncated, with `...` appended.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
 }
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "error parsing UUID ({})", self.tag)
+        write!(f, "error parsing UUID ({})", self.tag)?;
+        #[cfg(feature = "alloc")]
+        if !self.input.is_empty() {
+            write!(f, " from {:?}", self.input)?;
+        }
+        write!(f, ": {}", self.kind)
     }
 }
 
@@ -1132,6 +3115,218 @@ impl core::error::Error for ParseError {
     }
 }
 
+/// The reason a [`TypedUuid`] failed to parse, as reported by
+/// [`ParseError::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input has the wrong length.
+    InvalidLength {
+        /// The length of the input, in bytes.
+        len: usize,
+    },
+
+    /// The input contains a character that can't appear in a UUID.
+    InvalidChar {
+        /// The invalid character.
+        character: char,
+
+        /// The byte index of the character within the input.
+        index: usize,
+    },
+
+    /// The input has a valid length and valid characters, but its
+    /// hyphen-separated groups aren't 8, 4, 4, 4 and 12 digits long.
+    InvalidGroups,
+}
+
+impl ParseErrorKind {
+    /// Works out why `input`, which `uuid` failed to parse, is invalid.
+    fn classify(input: &str) -> Self {
+        // Skip over the URN prefix and braces that uuid accepts, so they
+        // aren't reported as invalid characters.
+        let (offset, body) = if let Some(body) = input.strip_prefix("urn:uuid:") {
+            (9, body)
+        } else if let Some(body) = input
+            .strip_prefix('{')
+            .and_then(|rest| rest.strip_suffix('}'))
+        {
+            (1, body)
+        } else {
+            (0, input)
+        };
+
+        if let Some((index, character)) = body
+            .char_indices()
+            .find(|&(_, c)| !(c.is_ascii_hexdigit() || c == '-'))
+        {
+            return Self::InvalidChar {
+                character,
+                index: offset + index,
+            };
+        }
+
+        match input.len() {
+            // The simple, hyphenated, braced and URN forms respectively.
+            32 | 36 | 38 | 45 => Self::InvalidGroups,
+            len => Self::InvalidLength { len },
+        }
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { len } => write!(f, "invalid length {len}"),
+            Self::InvalidChar { character, index } => {
+                write!(f, "invalid character {character:?} at index {index}")
+            }
+            Self::InvalidGroups => f.write_str(
+                "invalid groups (expected hyphen-separated groups of 8, 4, 4, 4 and 12 digits)",
+            ),
+        }
+    }
+}
+
+/// An error that occurred while parsing a [`TypedUuid`] and checking it, with
+/// methods like [`TypedUuid::parse_prefixed`], [`TypedUuid::parse_version`]
+/// and [`TypedUuid::parse_non_nil`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CheckedParseError {
+    /// The input isn't a valid UUID.
+    Parse(ParseError),
+
+    /// The input isn't prefixed by the kind's tag, as expected by
+    /// [`TypedUuid::parse_prefixed`].
+    TagMismatch {
+        /// The tag of the UUID that failed to parse.
+        tag: TypedUuidTag,
+    },
+
+    /// The UUID is valid, but has another version than the one expected by
+    /// [`TypedUuid::parse_version`].
+    WrongVersion {
+        /// The tag of the UUID that failed to parse.
+        tag: TypedUuidTag,
+
+        /// The expected version number.
+        expected: u8,
+
+        /// The UUID's version number.
+        found: u8,
+    },
+
+    /// The UUID is nil, which isn't allowed by [`TypedUuid::parse_non_nil`].
+    NilDisallowed {
+        /// The tag of the UUID that failed to parse.
+        tag: TypedUuidTag,
+    },
+}
+
+impl CheckedParseError {
+    /// Returns the tag of the UUID that failed to parse.
+    pub fn tag(&self) -> &TypedUuidTag {
+        match self {
+            Self::Parse(error) => &error.tag,
+            Self::TagMismatch { tag }
+            | Self::WrongVersion { tag, .. }
+            | Self::NilDisallowed { tag } => tag,
+        }
+    }
+}
+
+impl From<ParseError> for CheckedParseError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl fmt::Display for CheckedParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(error) => error.fmt(f),
+            Self::TagMismatch { tag } => {
+                write!(
+                    f,
+                    "error parsing UUID ({tag}): missing or mismatched tag prefix"
+                )
+            }
+            Self::WrongVersion {
+                tag,
+                expected,
+                found,
+            } => write!(
+                f,
+                "error parsing UUID ({tag}): expected a version {expected} UUID, \
+                 found version {found}"
+            ),
+            Self::NilDisallowed { tag } => {
+                write!(f, "error parsing UUID ({tag}): the nil UUID is not allowed")
+            }
+        }
+    }
+}
+
+impl core::error::Error for CheckedParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Parse(error) => error.source(),
+            Self::TagMismatch { .. } | Self::WrongVersion { .. } | Self::NilDisallowed { .. } => {
+                None
+            }
+        }
+    }
+}
+
+/// An error that occurred while parsing many [`TypedUuid`]s at once, with
+/// methods like [`TypedUuid::parse_many`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ParseManyError {
+    /// The index of the first input that failed to parse.
+    pub index: usize,
+
+    /// The error for that input.
+    pub error: ParseError,
+}
+
+impl fmt::Display for ParseManyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error parsing UUID at index {}", self.index)
+    }
+}
+
+impl core::error::Error for ParseManyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error returned by [`TypedUuid::from_bytes_slice`] if the length of its
+/// input isn't a multiple of 16 bytes.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ByteLengthError {
+    /// The length of the input, in bytes.
+    pub len: usize,
+
+    /// The tag of the UUIDs that failed to parse.
+    pub tag: TypedUuidTag,
+}
+
+impl fmt::Display for ByteLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "error parsing UUIDs ({}): byte length {} is not a multiple of 16",
+            self.tag, self.len
+        )
+    }
+}
+
+impl core::error::Error for ByteLengthError {}
+
 /// A trait abstracting over typed and untyped UUIDs.
 ///
 /// This can be used to write code that's generic over [`TypedUuid`], [`Uuid`], and other types that
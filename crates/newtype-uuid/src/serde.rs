@@ -0,0 +1,379 @@
+//! Serde helpers for choosing a [`TypedUuid`] wire format explicitly.
+//!
+//! By default, [`TypedUuid`] serializes exactly like [`Uuid`]: as a hyphenated
+//! string for human-readable formats, and as 16 raw bytes otherwise. The
+//! modules in here can be used with `#[serde(with = "...")]` to pick a single
+//! representation regardless of [`Serializer::is_human_readable`]:
+//!
+//! - [`bytes`]: 16 raw bytes.
+//! - [`string`]: the hyphenated form, e.g. `"dffc3068-1cd6-47d5-b2f3-636b41b07084"`.
+//! - [`prefixed`]: the hyphenated form prefixed by the kind's tag, e.g.
+//!   `"my_kind:dffc3068-1cd6-47d5-b2f3-636b41b07084"`.
+//! - [`compact`]: a 22-character, URL-safe base64 encoding without padding,
+//!   e.g. `"3_wwaBzWR9Wy82NrQbBwhA"`.
+//!
+//! # Examples
+//!
+//! ```
+//! use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+//! use serde::{Deserialize, Serialize};
+//!
+//! enum MyKind {}
+//!
+//! impl TypedUuidKind for MyKind {
+//!     fn tag() -> TypedUuidTag {
+//!         const TAG: TypedUuidTag = TypedUuidTag::new("my_kind");
+//!         TAG
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "newtype_uuid::serde::prefixed")]
+//!     id: TypedUuid<MyKind>,
+//!     #[serde(with = "newtype_uuid::serde::compact")]
+//!     other: TypedUuid<MyKind>,
+//! }
+//!
+//! let uuid: TypedUuid<MyKind> = "dffc3068-1cd6-47d5-b2f3-636b41b07084".parse().unwrap();
+//! let record = Record {
+//!     id: uuid,
+//!     other: uuid,
+//! };
+//! assert_eq!(
+//!     serde_json::to_string(&record).unwrap(),
+//!     r#"{"id":"my_kind:dffc3068-1cd6-47d5-b2f3-636b41b07084","other":"3_wwaBzWR9Wy82NrQbBwhA"}"#,
+//! );
+//! ```
+//!
+//! [`Serializer::is_human_readable`]: ::serde::Serializer::is_human_readable
+
+use crate::{GenericUuid, TypedUuid, TypedUuidKind};
+use core::{fmt, marker::PhantomData};
+use uuid::Uuid;
+
+/// Serialize a [`TypedUuid`] as 16 raw bytes.
+///
+/// This uses [`Serializer::serialize_bytes`], so formats with a native byte
+/// string type (such as CBOR) store the UUID as one. Deserialization also
+/// accepts a sequence of 16 integers, which is how formats without a byte
+/// string type (such as JSON) represent bytes.
+///
+/// [`Serializer::serialize_bytes`]: ::serde::Serializer::serialize_bytes
+pub mod bytes {
+    use super::*;
+    use ::serde::{
+        Deserializer, Serializer,
+        de::{self, SeqAccess, Visitor},
+    };
+
+    /// Serialize a [`TypedUuid`] as 16 raw bytes.
+    pub fn serialize<T, S>(uuid: &TypedUuid<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: TypedUuidKind,
+        S: Serializer,
+    {
+        serializer.serialize_bytes(uuid.as_bytes())
+    }
+
+    /// Deserialize 16 raw bytes as a [`TypedUuid`].
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<TypedUuid<T>, D::Error>
+    where
+        T: TypedUuidKind,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    }
+
+    struct BytesVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: TypedUuidKind> Visitor<'de> for BytesVisitor<T> {
+        type Value = TypedUuid<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "16 bytes for a {} UUID", T::tag())
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            let uuid = Uuid::from_slice(v).map_err(|_| E::invalid_length(v.len(), &self))?;
+            Ok(TypedUuid::from_untyped_uuid(uuid))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = [0u8; 16];
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                return Err(de::Error::invalid_length(17, &self));
+            }
+            Ok(TypedUuid::from_bytes(bytes))
+        }
+    }
+}
+
+/// Serialize a [`TypedUuid`] as a hyphenated string.
+///
+/// This is the same format [`TypedUuid`] uses for human-readable formats, but
+/// it is also used for formats that aren't human-readable.
+pub mod string {
+    use super::*;
+    use ::serde::{
+        Deserializer, Serializer,
+        de::{self, Visitor},
+    };
+
+    /// Serialize a [`TypedUuid`] as a hyphenated string.
+    pub fn serialize<T, S>(uuid: &TypedUuid<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: TypedUuidKind,
+        S: Serializer,
+    {
+        let mut buf = Uuid::encode_buffer();
+        serializer.serialize_str(uuid.as_untyped_uuid().hyphenated().encode_lower(&mut buf))
+    }
+
+    /// Deserialize a string as a [`TypedUuid`].
+    ///
+    /// Any format accepted by [`Uuid::parse_str`] is accepted here.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<TypedUuid<T>, D::Error>
+    where
+        T: TypedUuidKind,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StringVisitor(PhantomData))
+    }
+
+    struct StringVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: TypedUuidKind> Visitor<'de> for StringVisitor<T> {
+        type Value = TypedUuid<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a {} UUID string", T::tag())
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            let uuid = Uuid::try_parse_ascii(v)
+                .map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))?;
+            Ok(TypedUuid::from_untyped_uuid(uuid))
+        }
+    }
+}
+
+/// Serialize a [`TypedUuid`] as a hyphenated string, prefixed by its tag.
+///
+/// The tag and the UUID are separated by a `:`, for example
+/// `"my_kind:dffc3068-1cd6-47d5-b2f3-636b41b07084"`. Deserialization requires
+/// the tag to match [`TypedUuidKind::tag`].
+pub mod prefixed {
+    use super::*;
+    use ::serde::{
+        Deserializer, Serializer,
+        de::{self, Visitor},
+    };
+
+    /// Serialize a [`TypedUuid`] as a tag-prefixed, hyphenated string.
+    pub fn serialize<T, S>(uuid: &TypedUuid<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: TypedUuidKind,
+        S: Serializer,
+    {
+        serializer.collect_str(&Prefixed(uuid))
+    }
+
+    /// Deserialize a tag-prefixed string as a [`TypedUuid`].
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<TypedUuid<T>, D::Error>
+    where
+        T: TypedUuidKind,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(PrefixedVisitor(PhantomData))
+    }
+
+    struct Prefixed<'a, T: TypedUuidKind>(&'a TypedUuid<T>);
+
+    impl<T: TypedUuidKind> fmt::Display for Prefixed<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}{}{}", T::tag(), SEPARATOR, self.0)
+        }
+    }
+
+    const SEPARATOR: char = ':';
+
+    struct PrefixedVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: TypedUuidKind> Visitor<'de> for PrefixedVisitor<T> {
+        type Value = TypedUuid<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a UUID string prefixed with `{}{}`", T::tag(), SEPARATOR)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let Some((tag, uuid)) = v.split_once(SEPARATOR) else {
+                return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+            };
+            if tag != T::tag().as_str() {
+                return Err(E::custom(format_args!(
+                    "expected tag `{}`, found `{}`",
+                    T::tag(),
+                    tag,
+                )));
+            }
+            uuid.parse().map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            let s = core::str::from_utf8(v)
+                .map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))?;
+            self.visit_str(s)
+        }
+    }
+}
+
+/// Serialize a [`TypedUuid`] as a compact, base64-encoded string.
+///
+/// The encoding is the URL-safe base64 alphabet from [RFC 4648 section 5],
+/// without padding. This always produces 22 characters.
+///
+/// [RFC 4648 section 5]: https://www.rfc-editor.org/rfc/rfc4648#section-5
+pub mod compact {
+    use super::*;
+    use ::serde::{
+        Deserializer, Serializer,
+        de::{self, Visitor},
+    };
+
+    /// Serialize a [`TypedUuid`] as a 22-character base64 string.
+    pub fn serialize<T, S>(uuid: &TypedUuid<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: TypedUuidKind,
+        S: Serializer,
+    {
+        let mut buf = [0u8; ENCODED_LEN];
+        serializer.serialize_str(encode(uuid.as_bytes(), &mut buf))
+    }
+
+    /// Deserialize a 22-character base64 string as a [`TypedUuid`].
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<TypedUuid<T>, D::Error>
+    where
+        T: TypedUuidKind,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(CompactVisitor(PhantomData))
+    }
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    const ENCODED_LEN: usize = 22;
+
+    fn encode<'a>(bytes: &uuid::Bytes, buf: &'a mut [u8; ENCODED_LEN]) -> &'a str {
+        // 128 bits are encoded as 21 full 6-bit groups, plus a final group with
+        // the 2 remaining bits in its high bits.
+        let value = u128::from_be_bytes(*bytes);
+        for (i, out) in buf.iter_mut().enumerate() {
+            let shift = 128 - 6 * (i as i32 + 1);
+            let sextet = if shift >= 0 {
+                (value >> shift) & 0x3f
+            } else {
+                (value << -shift) & 0x3f
+            };
+            *out = ALPHABET[sextet as usize];
+        }
+        core::str::from_utf8(buf).expect("base64 alphabet is ASCII")
+    }
+
+    fn decode(s: &[u8]) -> Option<uuid::Bytes> {
+        if s.len() != ENCODED_LEN {
+            return None;
+        }
+        let mut value = 0u128;
+        for (i, &c) in s.iter().enumerate() {
+            let sextet = match c {
+                b'A'..=b'Z' => c - b'A',
+                b'a'..=b'z' => c - b'a' + 26,
+                b'0'..=b'9' => c - b'0' + 52,
+                b'-' => 62,
+                b'_' => 63,
+                _ => return None,
+            } as u128;
+            if i < ENCODED_LEN - 1 {
+                value = (value << 6) | sextet;
+            } else {
+                // Only the high 2 bits of the final group carry data; the rest
+                // must be zero for the encoding to be canonical.
+                if sextet & 0x0f != 0 {
+                    return None;
+                }
+                value = (value << 2) | (sextet >> 4);
+            }
+        }
+        Some(value.to_be_bytes())
+    }
+
+    struct CompactVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: TypedUuidKind> Visitor<'de> for CompactVisitor<T> {
+        type Value = TypedUuid<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "a {}-character base64 string for a {} UUID",
+                ENCODED_LEN,
+                T::tag()
+            )
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let bytes = decode(v.as_bytes())
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))?;
+            Ok(TypedUuid::from_bytes(bytes))
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            let bytes =
+                decode(v).ok_or_else(|| E::invalid_value(de::Unexpected::Bytes(v), &self))?;
+            Ok(TypedUuid::from_bytes(bytes))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_encode_decode() {
+            let cases: [(u128, &str); 3] = [
+                (0, "AAAAAAAAAAAAAAAAAAAAAA"),
+                (u128::MAX, "_____________________w"),
+                (
+                    0xdffc3068_1cd6_47d5_b2f3_636b41b07084,
+                    "3_wwaBzWR9Wy82NrQbBwhA",
+                ),
+            ];
+            for (value, expected) in cases {
+                let mut buf = [0u8; ENCODED_LEN];
+                let encoded = encode(&value.to_be_bytes(), &mut buf);
+                assert_eq!(encoded, expected, "encoding {value:#x}");
+                assert_eq!(
+                    decode(encoded.as_bytes()),
+                    Some(value.to_be_bytes()),
+                    "decoding {expected}",
+                );
+            }
+
+            // Wrong length, bad characters, and non-canonical trailing bits are
+            // all rejected.
+            assert_eq!(decode(b"AAAA"), None);
+            assert_eq!(decode(b"AAAAAAAAAAAAAAAAAAAAA="), None);
+            assert_eq!(decode(b"AAAAAAAAAAAAAAAAAAAAAB"), None);
+        }
+    }
+}
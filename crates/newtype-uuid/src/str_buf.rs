@@ -0,0 +1,398 @@
+use crate::{TypedUuid, TypedUuidKind, TypedUuidTag};
+use core::{borrow::Borrow, fmt, hash, ops::Deref, str::FromStr};
+use uuid::Uuid;
+
+/// The length of a hyphenated UUID, e.g.
+/// `dffc3068-1cd6-47d5-b2f3-636b41b07084`.
+const UUID_LEN: usize = 36;
+
+/// A formatted [`TypedUuid`], stored inline without allocating.
+///
+/// This is returned by [`TypedUuid::to_str_buf`], which formats a UUID as a
+/// lowercase hyphenated string, and [`TypedUuid::to_prefixed_str_buf`], which
+/// additionally prefixes it with its kind's tag (e.g. `user:<uuid>`). It
+/// dereferences to [`str`], so it can be used wherever a string is expected,
+/// including in `no_std` environments and hot paths where calling
+/// `to_string()` would allocate.
+///
+/// The buffer holds up to [`MAX_LEN`](Self::MAX_LEN) bytes: a 36-byte UUID,
+/// optionally preceded by a tag of up to [`MAX_TAG_LEN`](Self::MAX_TAG_LEN)
+/// bytes and a colon.
+///
+/// With the `serde` feature, `TypedUuidStr` is serialized as a string. It can
+/// be deserialized from a hyphenated UUID, optionally prefixed by a tag, and
+/// is normalized to lowercase.
+///
+/// # Examples
+///
+/// ```
+/// use newtype_uuid::{TypedUuid, TypedUuidKind, TypedUuidTag};
+///
+/// enum UserKind {}
+/// impl TypedUuidKind for UserKind {
+///     fn tag() -> TypedUuidTag {
+///         const TAG: TypedUuidTag = TypedUuidTag::new("user");
+///         TAG
+///     }
+/// }
+///
+/// let uuid = TypedUuid::<UserKind>::from_u128(0xdffc3068_1cd6_47d5_b2f3_636b41b07084);
+///
+/// let s = uuid.to_str_buf();
+/// assert_eq!(&*s, "dffc3068-1cd6-47d5-b2f3-636b41b07084");
+///
+/// let prefixed = uuid.to_prefixed_str_buf().expect("tag is short enough");
+/// assert_eq!(&*prefixed, "user:dffc3068-1cd6-47d5-b2f3-636b41b07084");
+/// ```
+#[derive(Clone, Copy)]
+pub struct TypedUuidStr {
+    buf: [u8; Self::MAX_LEN],
+    len: u8,
+}
+
+impl TypedUuidStr {
+    /// The maximum length of a tag that can be included in a `TypedUuidStr`.
+    ///
+    /// This is long enough for any realistic tag, e.g. `organization` or
+    /// `silo_user_ssh_key`, while keeping the buffer small enough to pass
+    /// around by value.
+    pub const MAX_TAG_LEN: usize = 64;
+
+    /// The maximum length of a `TypedUuidStr`, in bytes.
+    pub const MAX_LEN: usize = Self::MAX_TAG_LEN + 1 + UUID_LEN;
+
+    fn new(tag: Option<&str>, uuid: &Uuid) -> Option<Self> {
+        let mut buf = [0; Self::MAX_LEN];
+        let prefix_len = match tag {
+            Some(tag) if tag.len() > Self::MAX_TAG_LEN => return None,
+            Some(tag) => {
+                buf[..tag.len()].copy_from_slice(tag.as_bytes());
+                buf[tag.len()] = b':';
+                tag.len() + 1
+            }
+            None => 0,
+        };
+        let len = prefix_len + UUID_LEN;
+        uuid.hyphenated().encode_lower(&mut buf[prefix_len..len]);
+        Some(Self {
+            buf,
+            len: len as u8,
+        })
+    }
+
+    /// Returns the formatted UUID as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..usize::from(self.len)])
+            .expect("buffer only contains ASCII")
+    }
+
+    /// Returns the tag prefix, if this was created with
+    /// [`TypedUuid::to_prefixed_str_buf`].
+    pub fn tag(&self) -> Option<&str> {
+        self.as_str().split_once(':').map(|(tag, _)| tag)
+    }
+
+    /// Returns the hyphenated UUID, without any tag prefix.
+    pub fn uuid_str(&self) -> &str {
+        let s = self.as_str();
+        &s[s.len() - UUID_LEN..]
+    }
+}
+
+impl<T: TypedUuidKind> TypedUuid<T> {
+    /// Formats the UUID as a lowercase hyphenated string, stored inline
+    /// without allocating.
+    ///
+    /// The result is the same as calling `to_string()`, but works without
+    /// the `alloc` feature.
+    #[inline]
+    pub fn to_str_buf(&self) -> TypedUuidStr {
+        TypedUuidStr::new(None, &self.uuid).expect("UUID without a tag always fits")
+    }
+
+    /// Formats the UUID as a lowercase hyphenated string prefixed by its
+    /// kind's tag, e.g. `user:dffc3068-1cd6-47d5-b2f3-636b41b07084`, stored
+    /// inline without allocating.
+    ///
+    /// This is the same format used by
+    /// [`serde::prefixed`](crate::serde::prefixed).
+    ///
+    /// Returns `None` if the tag is longer than
+    /// [`TypedUuidStr::MAX_TAG_LEN`] (64) bytes. Tags are normally much
+    /// shorter than that, e.g. `user` or `organization`.
+    #[inline]
+    pub fn to_prefixed_str_buf(&self) -> Option<TypedUuidStr> {
+        TypedUuidStr::new(Some(T::tag().as_str()), &self.uuid)
+    }
+}
+
+/// An error that occurred while parsing a [`TypedUuidStr`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct TypedUuidStrError {
+    /// The error message.
+    pub message: &'static str,
+}
+
+impl fmt::Display for TypedUuidStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error parsing UUID string: {}", self.message)
+    }
+}
+
+impl core::error::Error for TypedUuidStrError {}
+
+impl FromStr for TypedUuidStr {
+    type Err = TypedUuidStrError;
+
+    /// Parses a hyphenated UUID, optionally prefixed by a tag and a colon.
+    ///
+    /// The UUID is normalized to lowercase.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (tag, uuid) = match s.split_once(':') {
+            Some((tag, uuid)) => {
+                TypedUuidTag::validate(tag).map_err(|message| TypedUuidStrError { message })?;
+                (Some(tag), uuid)
+            }
+            None => (None, s),
+        };
+        if uuid.len() != UUID_LEN {
+            return Err(TypedUuidStrError {
+                message: "UUID must be in hyphenated form",
+            });
+        }
+        let uuid = Uuid::try_parse(uuid).map_err(|_| TypedUuidStrError {
+            message: "invalid UUID",
+        })?;
+        Self::new(tag, &uuid).ok_or(TypedUuidStrError {
+            message: "tag is too long",
+        })
+    }
+}
+
+impl Deref for TypedUuidStr {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for TypedUuidStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for TypedUuidStr {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl Borrow<str> for TypedUuidStr {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for TypedUuidStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl fmt::Debug for TypedUuidStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for TypedUuidStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for TypedUuidStr {}
+
+impl PartialEq<str> for TypedUuidStr {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for TypedUuidStr {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for TypedUuidStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TypedUuidStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl hash::Hash for TypedUuidStr {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // Hash the same way as `str`, so that `Borrow<str>` lookups work.
+        self.as_str().hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_imp {
+    use super::*;
+    use ::serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{self, Visitor},
+    };
+
+    impl Serialize for TypedUuidStr {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for TypedUuidStr {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_str(StrVisitor)
+        }
+    }
+
+    struct StrVisitor;
+
+    impl Visitor<'_> for StrVisitor {
+        type Value = TypedUuidStr;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a hyphenated UUID, optionally prefixed by a tag")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(E::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum OrgKind {}
+
+    impl TypedUuidKind for OrgKind {
+        fn tag() -> TypedUuidTag {
+            const TAG: TypedUuidTag = TypedUuidTag::new("organization");
+            TAG
+        }
+    }
+
+    enum MaxKind {}
+
+    impl TypedUuidKind for MaxKind {
+        fn tag() -> TypedUuidTag {
+            const TAG: TypedUuidTag = TypedUuidTag::new(MAX_TAG);
+            TAG
+        }
+    }
+
+    enum LongKind {}
+
+    impl TypedUuidKind for LongKind {
+        fn tag() -> TypedUuidTag {
+            const TAG: TypedUuidTag = TypedUuidTag::new(LONG_TAG);
+            TAG
+        }
+    }
+
+    // 64 and 65 bytes long, respectively.
+    const MAX_TAG: &str = "a_tag_that_is_exactly_as_long_as_the_maximum_tag_length_01234567";
+    const LONG_TAG: &str = "a_tag_that_is_one_byte_longer_than_the_maximum_tag_length_0123456";
+
+    const UUID: u128 = 0xdffc3068_1cd6_47d5_b2f3_636b41b07084;
+    const UUID_STR: &str = "dffc3068-1cd6-47d5-b2f3-636b41b07084";
+
+    #[test]
+    fn test_to_str_buf() {
+        assert_eq!(MAX_TAG.len(), TypedUuidStr::MAX_TAG_LEN);
+        assert_eq!(LONG_TAG.len(), TypedUuidStr::MAX_TAG_LEN + 1);
+
+        let uuid = TypedUuid::<OrgKind>::from_u128(UUID);
+        let s = uuid.to_str_buf();
+        assert_eq!(s, UUID_STR);
+        assert_eq!(s.tag(), None);
+        assert_eq!(s.uuid_str(), UUID_STR);
+
+        let prefixed = uuid.to_prefixed_str_buf().unwrap();
+        assert_eq!(prefixed.strip_prefix("organization:"), Some(UUID_STR));
+        assert_eq!(prefixed.tag(), Some("organization"));
+        assert_eq!(prefixed.uuid_str(), UUID_STR);
+
+        let prefixed = TypedUuid::<MaxKind>::from_u128(UUID)
+            .to_prefixed_str_buf()
+            .unwrap();
+        assert_eq!(prefixed.len(), TypedUuidStr::MAX_LEN);
+        assert_eq!(prefixed.tag(), Some(MAX_TAG));
+        assert_eq!(prefixed.uuid_str(), UUID_STR);
+
+        let uuid = TypedUuid::<LongKind>::from_u128(UUID);
+        assert_eq!(uuid.to_str_buf(), UUID_STR);
+        assert!(uuid.to_prefixed_str_buf().is_none());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let s: TypedUuidStr = "DFFC3068-1CD6-47D5-B2F3-636B41B07084".parse().unwrap();
+        assert_eq!(s, UUID_STR);
+
+        let s: TypedUuidStr = "organization:dffc3068-1cd6-47d5-b2f3-636b41b07084"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            s,
+            TypedUuid::<OrgKind>::from_u128(UUID)
+                .to_prefixed_str_buf()
+                .unwrap()
+        );
+
+        for (input, message) in [
+            ("", "UUID must be in hyphenated form"),
+            (
+                "dffc30681cd647d5b2f3636b41b07084",
+                "UUID must be in hyphenated form",
+            ),
+            ("dffc3068-1cd6-47d5-b2f3-636b41b0708g", "invalid UUID"),
+            (
+                "a_tag_that_is_one_byte_longer_than_the_maximum_tag_length_0123456:\
+                 dffc3068-1cd6-47d5-b2f3-636b41b07084",
+                "tag is too long",
+            ),
+            (
+                "1a:dffc3068-1cd6-47d5-b2f3-636b41b07084",
+                "first character of tag must be an ASCII letter or underscore",
+            ),
+        ] {
+            let error = input.parse::<TypedUuidStr>().unwrap_err();
+            assert_eq!(error.message, message, "input: {input:?}");
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Tests for the `wasm-bindgen02` feature.
+//!
+//! These tests run under Node.js with `wasm-bindgen-test-runner`:
+//!
+//! ```text
+//! CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//!     cargo test -p newtype-uuid --target wasm32-unknown-unknown \
+//!     --features wasm-bindgen02 --test wasm_bindgen
+//! ```
+
+#![cfg(all(target_arch = "wasm32", feature = "wasm-bindgen02"))]
+
+use newtype_uuid_macros::impl_typed_uuid_kinds;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+impl_typed_uuid_kinds! {
+    settings = {
+        wasm_bindgen02 = {},
+    },
+    kinds = {
+        User = {},
+    }
+}
+
+const UUID_STR: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+#[wasm_bindgen(inline_js = r#"
+export function echo(id) {
+    if (typeof id !== "string") {
+        throw new TypeError(`expected a string, found ${typeof id}`);
+    }
+    return id;
+}
+
+export function echo_option(id) {
+    return id;
+}
+
+export function to_upper_case(id) {
+    return id.toUpperCase();
+}
+
+export function invalid() {
+    return "not-a-uuid";
+}
+"#)]
+extern "C" {
+    fn echo(id: UserUuid) -> UserUuid;
+    fn echo_option(id: Option<UserUuid>) -> Option<UserUuid>;
+    fn to_upper_case(id: UserUuid) -> UserUuid;
+    fn invalid() -> UserUuid;
+    #[wasm_bindgen(js_name = invalid)]
+    fn invalid_value() -> JsValue;
+}
+
+#[wasm_bindgen_test]
+fn test_round_trip() {
+    let uuid: UserUuid = UUID_STR.parse().unwrap();
+    assert_eq!(echo(uuid), uuid);
+
+    // Uppercase UUIDs are accepted as well.
+    assert_eq!(to_upper_case(uuid), uuid);
+}
+
+#[wasm_bindgen_test]
+fn test_option_round_trip() {
+    let uuid: UserUuid = UUID_STR.parse().unwrap();
+    assert_eq!(echo_option(Some(uuid)), Some(uuid));
+    assert_eq!(echo_option(None), None);
+}
+
+#[wasm_bindgen_test]
+fn test_into_js_value() {
+    let uuid: UserUuid = UUID_STR.parse().unwrap();
+    assert_eq!(JsValue::from(uuid).as_string().as_deref(), Some(UUID_STR));
+}
+
+#[wasm_bindgen_test]
+#[should_panic]
+fn test_invalid() {
+    // This throws a JavaScript error, mentioning the kind.
+    invalid();
+}
+
+#[wasm_bindgen_test]
+fn test_try_from_js_value() {
+    let uuid: UserUuid = UUID_STR.parse().unwrap();
+    assert_eq!(UserUuid::try_from(JsValue::from(uuid)).unwrap(), uuid);
+
+    // The errors mention the kind.
+    let error = JsValue::from(UserUuid::try_from(invalid_value()).unwrap_err());
+    let message = format!("{error:?}");
+    assert!(
+        message.contains(r#"invalid user UUID "not-a-uuid""#),
+        "unexpected error: {message}"
+    );
+
+    let error = JsValue::from(UserUuid::try_from(JsValue::from(1)).unwrap_err());
+    let message = format!("{error:?}");
+    assert!(
+        message.contains("expected a user UUID string"),
+        "unexpected error: {message}"
+    );
+}